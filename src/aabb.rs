@@ -0,0 +1,131 @@
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+/// Axis-aligned bounding box spanning `min` to `max`, both inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aabb<T: Numeric> {
+    pub min: Vector<T>,
+    pub max: Vector<T>,
+}
+impl<T: Numeric> Aabb<T> {
+    pub fn new(min: Vector<T>, max: Vector<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// Smallest box containing all `points`, `None` for an empty slice.
+    pub fn from_points(points: &[Vector<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(
+            rest.iter()
+                .fold(Self::new(first.clone(), first.clone()), |aabb, point| {
+                    aabb.include(point)
+                }),
+        )
+    }
+
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self {
+            min: self.min.min(&rhs.min),
+            max: self.max.max(&rhs.max),
+        }
+    }
+
+    /// Overlapping region of both boxes, `None` if they are disjoint.
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let aabb = Self {
+            min: self.min.max(&rhs.min),
+            max: self.max.min(&rhs.max),
+        };
+        if aabb.min.x > aabb.max.x || aabb.min.y > aabb.max.y || aabb.min.z > aabb.max.z {
+            return None;
+        }
+        Some(aabb)
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        self.min.x <= rhs.max.x
+            && self.max.x >= rhs.min.x
+            && self.min.y <= rhs.max.y
+            && self.max.y >= rhs.min.y
+            && self.min.z <= rhs.max.z
+            && self.max.z >= rhs.min.z
+    }
+
+    pub fn contains(&self, point: &Vector<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    /// Grows the box just enough to contain `point`.
+    pub fn include(&self, point: &Vector<T>) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    /// Grows the box by `margin` on every side.
+    pub fn expand(&self, margin: T) -> Self {
+        let margin = Vector::scalar(margin);
+        Self {
+            min: &self.min - &margin,
+            max: &self.max + &margin,
+        }
+    }
+
+    pub fn center(&self) -> Vector<T> {
+        (&self.min + &self.max) / (T::one() + T::one())
+    }
+
+    pub fn size(&self) -> Vector<T> {
+        &self.max - &self.min
+    }
+
+    /// Half the size along each axis.
+    pub fn extents(&self) -> Vector<T> {
+        self.size() / (T::one() + T::one())
+    }
+
+    pub fn surface_area(&self) -> T {
+        let size = self.size();
+        let two = T::one() + T::one();
+        two * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    pub fn volume(&self) -> T {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    /// Point of the box closest to `point`, which is `point` itself if it
+    /// lies inside.
+    pub fn closest_point(&self, point: &Vector<T>) -> Vector<T> {
+        point.max(&self.min).min(&self.max)
+    }
+
+    pub fn distance_squared(&self, point: &Vector<T>) -> T {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    /// Bounds of the box after applying `matrix` and then `translation`
+    /// (Arvo, "Transforming Axis-Aligned Bounding Boxes", Graphics Gems).
+    /// For rotations the result is the tightest box around the rotated one.
+    pub fn transform(&self, matrix: &Matrix<T>, translation: &Vector<T>) -> Self {
+        let bounds = |row: Vector<T>| {
+            let a = row.component_mul(&self.min);
+            let b = row.component_mul(&self.max);
+            let (low, high) = (a.min(&b), a.max(&b));
+            (low.x + low.y + low.z, high.x + high.y + high.z)
+        };
+        let (min_x, max_x) = bounds(matrix.row(0));
+        let (min_y, max_y) = bounds(matrix.row(1));
+        let (min_z, max_z) = bounds(matrix.row(2));
+        Self {
+            min: Vector::new(min_x, min_y, min_z) + translation,
+            max: Vector::new(max_x, max_y, max_z) + translation,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt::Debug,
+    ops::{Mul, MulAssign, Neg},
+};
+
+use crate::{
+    numeric::{Numeric, Real, Signed},
+    quaternion::Quaternion,
+    transform::Isometry,
+    vector::Vector,
+};
+
+/// Rigid transform encoded as `real + ε dual` with `ε² = 0`. For a rotation
+/// `r` followed by a translation `t` the parts are `r` and `t * r / 2`.
+#[derive(PartialEq, Eq)]
+pub struct DualQuaternion<T: Numeric> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+impl<T: Numeric> Default for DualQuaternion<T> {
+    fn default() -> Self {
+        Self {
+            real: Quaternion::default(),
+            dual: Quaternion::default(),
+        }
+    }
+}
+impl<T: Numeric> DualQuaternion<T> {
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            real: Quaternion::identity(),
+            dual: Quaternion::default(),
+        }
+    }
+
+    /// Conjugates both parts. For unit dual quaternions this is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    fn product(lhs: &Self, rhs: &Self) -> Self {
+        Self {
+            real: &lhs.real * &rhs.real,
+            dual: &lhs.real * &rhs.dual + &lhs.dual * &rhs.real,
+        }
+    }
+}
+impl<T: Real> DualQuaternion<T> {
+    /// Rotation by the unit quaternion `rotation` followed by `translation`.
+    pub fn from_rotation_translation(rotation: &Quaternion<T>, translation: &Vector<T>) -> Self {
+        let t = Quaternion::from_scalar_vector(T::zero(), translation);
+        Self {
+            real: rotation.clone(),
+            dual: &t * rotation / T::from_f64(2.0),
+        }
+    }
+
+    pub fn from_translation(translation: &Vector<T>) -> Self {
+        Self::from_rotation_translation(&Quaternion::identity(), translation)
+    }
+
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real.clone()
+    }
+
+    /// The translation of a unit dual quaternion.
+    pub fn translation(&self) -> Vector<T> {
+        (&self.dual * self.real.conjugate()).vector() * T::from_f64(2.0)
+    }
+
+    /// Scales to a unit real part and removes the component of the dual part
+    /// along it, so the result is a proper rigid transform.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.real.magnitude();
+        let real = &self.real / magnitude;
+        let dual = &self.dual / magnitude;
+        let dual = &dual - &real * real.dot(&dual);
+        Self { real, dual }
+    }
+
+    /// Transforms a point by a unit dual quaternion.
+    pub fn transform_point(&self, p: &Vector<T>) -> Vector<T> {
+        self.real.rotate(p) + self.translation()
+    }
+
+    /// Transforms a direction, ignoring the translation.
+    pub fn transform_vector(&self, v: &Vector<T>) -> Vector<T> {
+        self.real.rotate(v)
+    }
+
+    /// Screw linear interpolation, moving along the screw motion between two
+    /// unit dual quaternions at constant speed.
+    pub fn sclerp(&self, rhs: &Self, t: T) -> Self {
+        let rhs = if self.real.dot(&rhs.real) < T::zero() {
+            -rhs
+        } else {
+            rhs.clone()
+        };
+        let difference = self.conjugate() * rhs;
+        (self * difference.powf(t)).normalize()
+    }
+
+    // Raises a unit dual quaternion to a real power through its screw
+    // parameters: angle, pitch, direction and moment of the axis.
+    fn powf(&self, t: T) -> Self {
+        let two = T::from_f64(2.0);
+        let v = self.real.vector();
+        let s = v.magnitude();
+        if s < T::epsilon() {
+            return Self {
+                real: Quaternion::identity(),
+                dual: &self.dual * t,
+            };
+        }
+        let angle = two * s.atan2(self.real.w);
+        let direction = &v / s;
+        let pitch = -two * self.dual.w / s;
+        let moment = (self.dual.vector() - &direction * (pitch * self.real.w / two)) / s;
+
+        let half_angle = angle * t / two;
+        let half_pitch = pitch * t / two;
+        let (sin, cos) = (half_angle.sin(), half_angle.cos());
+        Self {
+            real: Quaternion::from_scalar_vector(cos, &(&direction * sin)),
+            dual: Quaternion::from_scalar_vector(
+                -half_pitch * sin,
+                &(moment * sin + direction * (half_pitch * cos)),
+            ),
+        }
+    }
+
+    /// Dual quaternion linear blending of weighted unit dual quaternions, as
+    /// used for skinning. Each transform is flipped into the hemisphere of the
+    /// first one before summing. Returns `None` if the weighted sum vanishes.
+    pub fn blend(transforms: &[(T, Self)]) -> Option<Self> {
+        let (_, pivot) = transforms.first()?;
+        let mut sum = Self::default();
+        for (weight, transform) in transforms {
+            let weight = if pivot.real.dot(&transform.real) < T::zero() {
+                -*weight
+            } else {
+                *weight
+            };
+            sum.real += &transform.real * weight;
+            sum.dual += &transform.dual * weight;
+        }
+        if sum.real.magnitude_squared() == T::zero() {
+            return None;
+        }
+        Some(sum.normalize())
+    }
+}
+
+impl<T: Real> From<&Isometry<T>> for DualQuaternion<T> {
+    fn from(isometry: &Isometry<T>) -> Self {
+        Self::from_rotation_translation(
+            &Quaternion::from(&isometry.rotation),
+            &isometry.translation,
+        )
+    }
+}
+impl<T: Real> From<Isometry<T>> for DualQuaternion<T> {
+    fn from(isometry: Isometry<T>) -> Self {
+        Self::from(&isometry)
+    }
+}
+impl<T: Real> From<&DualQuaternion<T>> for Isometry<T> {
+    fn from(dq: &DualQuaternion<T>) -> Self {
+        Isometry::new(dq.translation(), (&dq.real).into())
+    }
+}
+impl<T: Real> From<DualQuaternion<T>> for Isometry<T> {
+    fn from(dq: DualQuaternion<T>) -> Self {
+        Self::from(&dq)
+    }
+}
+
+impl<T: Numeric + Debug> Debug for DualQuaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DualQuaternion {{ real: {:?}, dual: {:?} }}",
+            self.real, self.dual
+        )
+    }
+}
+
+impl<T: Numeric + Clone> Clone for DualQuaternion<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.real.clone(), self.dual.clone())
+    }
+}
+
+impl<T: Numeric> Mul for DualQuaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        DualQuaternion::product(&self, &rhs)
+    }
+}
+impl<T: Numeric> Mul<&DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: &Self) -> Self::Output {
+        DualQuaternion::product(&self, rhs)
+    }
+}
+impl<T: Numeric> Mul<DualQuaternion<T>> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: DualQuaternion<T>) -> Self::Output {
+        DualQuaternion::product(self, &rhs)
+    }
+}
+impl<T: Numeric> Mul for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        DualQuaternion::product(self, rhs)
+    }
+}
+impl<T: Numeric> MulAssign for DualQuaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = DualQuaternion::product(self, &rhs);
+    }
+}
+impl<T: Numeric> MulAssign<&DualQuaternion<T>> for DualQuaternion<T> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = DualQuaternion::product(self, rhs);
+    }
+}
+
+impl<T: Signed> Neg for DualQuaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.real, -self.dual)
+    }
+}
+impl<T: Signed> Neg for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        DualQuaternion::new(-&self.real, -&self.dual)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+const DEFAULT_MAX_SWEEPS: usize = 32;
+
+/// Eigen-decomposition of a symmetric matrix, `a = vectors * diag(values) * vectors^T`.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T: Numeric> {
+    /// Eigenvalues in descending order.
+    pub values: Vector<T>,
+    /// Orthonormal eigenvectors stored as columns, matching `values`. The
+    /// basis is right-handed, i.e. a proper rotation.
+    pub vectors: Matrix<T>,
+    /// Number of Jacobi sweeps performed.
+    pub sweeps: usize,
+    /// Frobenius norm of the remaining off-diagonal part.
+    pub residual: T,
+    pub converged: bool,
+}
+
+impl<T: Real> Matrix<T> {
+    /// Eigen-decomposition of a symmetric matrix using cyclic Jacobi
+    /// rotations. Only the upper triangle is read.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T> {
+        self.symmetric_eigen_with(DEFAULT_MAX_SWEEPS, T::epsilon())
+    }
+
+    /// Like [`Matrix::symmetric_eigen`], stopping once the off-diagonal norm
+    /// drops below `tolerance` relative to the norm of the matrix or after
+    /// `max_sweeps` sweeps, whichever comes first.
+    pub fn symmetric_eigen_with(&self, max_sweeps: usize, tolerance: T) -> SymmetricEigen<T> {
+        let mut a = self.to_rows();
+        for (row, column) in [(1, 0), (2, 0), (2, 1)] {
+            a[row][column] = a[column][row];
+        }
+        let mut v = Matrix::unity().to_rows();
+        let scale = a
+            .iter()
+            .flatten()
+            .fold(T::zero(), |sum, &e| sum + e * e)
+            .sqrt();
+        let threshold = tolerance * scale;
+
+        let mut sweeps = 0;
+        let mut residual = off_diagonal_norm(&a);
+        while residual > threshold && sweeps < max_sweeps {
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] != T::zero() {
+                    rotate(&mut a, &mut v, p, q);
+                }
+            }
+            sweeps += 1;
+            residual = off_diagonal_norm(&a);
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| {
+            a[j][j]
+                .partial_cmp(&a[i][i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let column = |i: usize| Vector::new(v[0][i], v[1][i], v[2][i]);
+        let first = column(order[0]);
+        let second = column(order[1]);
+        let mut third = column(order[2]);
+        if first.cross(&second).dot(&third) < T::zero() {
+            third = -third;
+        }
+
+        SymmetricEigen {
+            values: Vector::new(
+                a[order[0]][order[0]],
+                a[order[1]][order[1]],
+                a[order[2]][order[2]],
+            ),
+            vectors: Matrix::from_columns(&first, &second, &third),
+            sweeps,
+            residual,
+            converged: residual <= threshold,
+        }
+    }
+}
+
+fn off_diagonal_norm<T: Real>(a: &[[T; 3]; 3]) -> T {
+    let two = T::from_f64(2.0);
+    (two * (a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2])).sqrt()
+}
+
+// Applies the Jacobi rotation zeroing a[p][q], a <- J^T a J and v <- v J.
+fn rotate<T: Real>(a: &mut [[T; 3]; 3], v: &mut [[T; 3]; 3], p: usize, q: usize) {
+    let one = T::one();
+    let theta = (a[q][q] - a[p][p]) / (T::from_f64(2.0) * a[p][q]);
+    let t = one / (theta.abs() + (theta * theta + one).sqrt());
+    let t = if theta < T::zero() { -t } else { t };
+    let c = one / (t * t + one).sqrt();
+    let s = t * c;
+    for row in a.iter_mut().chain(v.iter_mut()) {
+        let (kp, kq) = (row[p], row[q]);
+        row[p] = c * kp - s * kq;
+        row[q] = s * kp + c * kq;
+    }
+    let (row_p, row_q) = (a[p], a[q]);
+    for (k, (&pk, &qk)) in row_p.iter().zip(row_q.iter()).enumerate() {
+        a[p][k] = c * pk - s * qk;
+        a[q][k] = s * pk + c * qk;
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Sequence of rotation axes, the first letter naming the axis of the first
+/// rotation. Tait-Bryan orders use three distinct axes, proper Euler orders
+/// repeat the first axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+impl EulerOrder {
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    fn reversed(&self) -> Self {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+            repeated => *repeated,
+        }
+    }
+}
+
+/// Whether the rotations are about the fixed world axes (extrinsic) or about
+/// the axes of the already rotated frame (intrinsic).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EulerFrame {
+    #[default]
+    Intrinsic,
+    Extrinsic,
+}
+
+/// Three successive rotations in radians, applied in `order`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EulerAngles<T: Numeric> {
+    pub first: T,
+    pub second: T,
+    pub third: T,
+    pub order: EulerOrder,
+    pub frame: EulerFrame,
+}
+impl<T: Numeric> EulerAngles<T> {
+    pub fn new(first: T, second: T, third: T, order: EulerOrder, frame: EulerFrame) -> Self {
+        Self {
+            first,
+            second,
+            third,
+            order,
+            frame,
+        }
+    }
+}
+impl<T: Real> EulerAngles<T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let [i, j, k] = self.order.axes();
+        let first = Matrix::rotation(self.first, &axis(i));
+        let second = Matrix::rotation(self.second, &axis(j));
+        let third = Matrix::rotation(self.third, &axis(k));
+        match self.frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+
+    /// Extracts the angles of a rotation matrix for the given order and frame.
+    /// At gimbal lock only the sum or difference of the outer angles is
+    /// defined; the whole rotation is then assigned to a single outer angle
+    /// and the other one is set to zero.
+    pub fn from_matrix(m: &Matrix<T>, order: EulerOrder, frame: EulerFrame) -> Self {
+        match frame {
+            EulerFrame::Extrinsic => {
+                let (first, second, third) = extrinsic_angles(m, order);
+                Self::new(first, second, third, order, frame)
+            }
+            // Intrinsic rotations about i, j, k equal extrinsic rotations
+            // about k, j, i with the angles reversed.
+            EulerFrame::Intrinsic => {
+                let (first, second, third) = extrinsic_angles(m, order.reversed());
+                Self::new(third, second, first, order, frame)
+            }
+        }
+    }
+}
+
+impl<T: Real> From<&EulerAngles<T>> for Matrix<T> {
+    fn from(angles: &EulerAngles<T>) -> Self {
+        angles.to_matrix()
+    }
+}
+impl<T: Real> From<EulerAngles<T>> for Matrix<T> {
+    fn from(angles: EulerAngles<T>) -> Self {
+        angles.to_matrix()
+    }
+}
+
+fn axis<T: Numeric>(index: usize) -> Vector<T> {
+    match index {
+        0 => Vector::x_axis(),
+        1 => Vector::y_axis(),
+        _ => Vector::z_axis(),
+    }
+}
+
+fn element<T: Numeric>(m: &Matrix<T>, row: usize, column: usize) -> T {
+    match (row, column) {
+        (0, 0) => m.m11,
+        (0, 1) => m.m12,
+        (0, 2) => m.m13,
+        (1, 0) => m.m21,
+        (1, 1) => m.m22,
+        (1, 2) => m.m23,
+        (2, 0) => m.m31,
+        (2, 1) => m.m32,
+        _ => m.m33,
+    }
+}
+
+// Shoemake, "Euler Angle Conversion", Graphics Gems IV. Odd axis permutations
+// are handled as their even counterpart with negated angles.
+fn extrinsic_angles<T: Real>(m: &Matrix<T>, order: EulerOrder) -> (T, T, T) {
+    let [i, j, last] = order.axes();
+    let odd = j != (i + 1) % 3;
+    let k = 3 - i - j;
+    let m = |row, column| element(m, row, column);
+    let threshold = T::from_f64(16.0) * T::epsilon();
+    let repeated = last == i;
+    let (first, second, third) = if repeated {
+        let sy = (m(i, j) * m(i, j) + m(i, k) * m(i, k)).sqrt();
+        if sy > threshold {
+            (
+                m(i, j).atan2(m(i, k)),
+                sy.atan2(m(i, i)),
+                m(j, i).atan2(-m(k, i)),
+            )
+        } else {
+            ((-m(j, k)).atan2(m(j, j)), sy.atan2(m(i, i)), T::zero())
+        }
+    } else {
+        let cy = (m(i, i) * m(i, i) + m(j, i) * m(j, i)).sqrt();
+        if cy > threshold {
+            (
+                m(k, j).atan2(m(k, k)),
+                (-m(k, i)).atan2(cy),
+                m(j, i).atan2(m(i, i)),
+            )
+        } else {
+            ((-m(j, k)).atan2(m(j, j)), (-m(k, i)).atan2(cy), T::zero())
+        }
+    };
+    let (first, second, third) = if odd {
+        (-first, -second, -third)
+    } else {
+        (first, second, third)
+    };
+    // Keep the middle angle of proper Euler orders within [0, pi] through the
+    // equivalent representation (a + pi, -b, c + pi).
+    if repeated && second < T::zero() {
+        (wrap(first + T::pi()), -second, wrap(third + T::pi()))
+    } else {
+        (first, second, third)
+    }
+}
+
+fn wrap<T: Real>(angle: T) -> T {
+    if angle > T::pi() {
+        angle - T::pi() - T::pi()
+    } else {
+        angle
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    aabb::Aabb,
+    matrix4::Matrix4,
+    numeric::{Numeric, Real},
+    obb::Obb,
+    plane::Plane,
+    projection::{DepthRange, Handedness},
+    sphere::Sphere,
+    vector::Vector,
+};
+
+/// Result of testing a volume against a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Intersecting,
+    Outside,
+}
+
+/// View frustum bounded by six planes whose normals point inwards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frustum<T: Numeric> {
+    pub left: Plane<T>,
+    pub right: Plane<T>,
+    pub bottom: Plane<T>,
+    pub top: Plane<T>,
+    pub near: Plane<T>,
+    pub far: Plane<T>,
+}
+impl<T: Numeric> Frustum<T> {
+    pub fn planes(&self) -> [&Plane<T>; 6] {
+        [
+            &self.left,
+            &self.right,
+            &self.bottom,
+            &self.top,
+            &self.near,
+            &self.far,
+        ]
+    }
+}
+impl<T: Real> Frustum<T> {
+    /// Extracts the planes of a combined `projection * view` transform
+    /// (Gribb and Hartmann, "Fast Extraction of Viewing Frustum Planes from
+    /// the World-View-Projection Matrix"). `depth` has to match the depth
+    /// range the projection was built for. With a projection alone the
+    /// frustum is in view space. With reversed z the near and far planes swap
+    /// places, so `near` holds the far plane and `far` the near one. An
+    /// infinite far plane is kept as a plane that contains everything.
+    pub fn from_matrix(m: &Matrix4<T>, depth: DepthRange) -> Self {
+        let row = |r: [T; 4]| (Vector::new(r[0], r[1], r[2]), r[3]);
+        let (x, xw) = row([m.m11, m.m12, m.m13, m.m14]);
+        let (y, yw) = row([m.m21, m.m22, m.m23, m.m24]);
+        let (z, zw) = row([m.m31, m.m32, m.m33, m.m34]);
+        let (w, ww) = row([m.m41, m.m42, m.m43, m.m44]);
+        // The clip-space condition `n.dot(p) + d >= 0` as a plane.
+        let plane = |normal: Vector<T>, d: T| normalized(Plane::new(normal, -d));
+        let near = match depth {
+            DepthRange::NegativeOneToOne => plane(&w + &z, ww + zw),
+            DepthRange::ZeroToOne => plane(z.clone(), zw),
+        };
+        Self {
+            left: plane(&w + &x, ww + xw),
+            right: plane(&w - &x, ww - xw),
+            bottom: plane(&w + &y, ww + yw),
+            top: plane(&w - &y, ww - yw),
+            near,
+            far: plane(&w - &z, ww - zw),
+        }
+    }
+
+    /// Frustum of a perspective camera at `eye` looking along `direction`,
+    /// matching `Matrix4::perspective` combined with `Matrix4::look_to`.
+    /// Returns `None` under the same conditions as `Matrix4::look_to`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_camera(
+        eye: &Vector<T>,
+        direction: &Vector<T>,
+        up: &Vector<T>,
+        fovy: T,
+        aspect: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+    ) -> Option<Self> {
+        let camera = Matrix4::look_to_inverse(eye, direction, up, handedness)?;
+        let side = Vector::new(camera.m11, camera.m21, camera.m31);
+        let up = Vector::new(camera.m12, camera.m22, camera.m32);
+        let forward =
+            Vector::new(camera.m13, camera.m23, camera.m33) * handedness.forward_sign::<T>();
+        let half_height = (fovy / T::from_f64(2.0)).tan();
+        let half_width = half_height * aspect;
+        let through_eye = |normal: Vector<T>| Plane::from_point_normal(eye, normal.normalize());
+        Some(Self {
+            left: through_eye(&forward * half_width + &side),
+            right: through_eye(&forward * half_width - &side),
+            bottom: through_eye(&forward * half_height + &up),
+            top: through_eye(&forward * half_height - &up),
+            near: Plane::from_point_normal(&(eye + &forward * near), forward.clone()),
+            far: Plane::from_point_normal(&(eye + &forward * far), -forward),
+        })
+    }
+
+    pub fn contains_point(&self, point: &Vector<T>) -> bool {
+        self.planes()
+            .iter()
+            .all(|plane| distance(plane, point) >= T::zero())
+    }
+
+    pub fn classify_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        self.classify(&sphere.center, |_| sphere.radius)
+    }
+
+    pub fn classify_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        let extents = aabb.extents();
+        self.classify(&aabb.center(), |normal| extents.dot(&normal.abs()))
+    }
+
+    pub fn classify_obb(&self, obb: &Obb<T>) -> Containment {
+        let e = &obb.half_extents;
+        self.classify(&obb.center, |normal| {
+            e.x * normal.dot(&obb.axis(0)).abs()
+                + e.y * normal.dot(&obb.axis(1)).abs()
+                + e.z * normal.dot(&obb.axis(2)).abs()
+        })
+    }
+
+    // Compares the distance of `center` to each plane with the projected
+    // radius of the volume onto the plane normal. Volumes straddling two
+    // planes outside a frustum corner are conservatively reported as
+    // intersecting.
+    fn classify(&self, center: &Vector<T>, radius: impl Fn(&Vector<T>) -> T) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in self.planes() {
+            let distance = distance(plane, center);
+            let radius = radius(&plane.normal);
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance < radius {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// The eight corners, those on `near` first, each in the order
+    /// bottom-left, bottom-right, top-left, top-right. For a frustum
+    /// extracted from a reversed-z matrix the far corners come first. Returns
+    /// `None` if three of the planes do not meet in a point, e.g. for an
+    /// infinite far plane.
+    pub fn corners(&self) -> Option<[Vector<T>; 8]> {
+        let corner = |depth: &Plane<T>, vertical: &Plane<T>, horizontal: &Plane<T>| {
+            Plane::intersect_planes(depth, vertical, horizontal)
+        };
+        Some([
+            corner(&self.near, &self.bottom, &self.left)?,
+            corner(&self.near, &self.bottom, &self.right)?,
+            corner(&self.near, &self.top, &self.left)?,
+            corner(&self.near, &self.top, &self.right)?,
+            corner(&self.far, &self.bottom, &self.left)?,
+            corner(&self.far, &self.bottom, &self.right)?,
+            corner(&self.far, &self.top, &self.left)?,
+            corner(&self.far, &self.top, &self.right)?,
+        ])
+    }
+}
+
+// Signed distance for unit normals. Degenerate planes with a zero normal,
+// such as an infinite far plane, yield a constant instead of NaN.
+fn distance<T: Real>(plane: &Plane<T>, point: &Vector<T>) -> T {
+    plane.normal.dot(point) - plane.offset
+}
+
+fn normalized<T: Real>(plane: Plane<T>) -> Plane<T> {
+    if plane.normal.magnitude_squared() == T::zero() {
+        plane
+    } else {
+        plane.normalize()
+    }
+}
@@ -0,0 +1,382 @@
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    support::SupportMap,
+    triangle::Triangle,
+    vector::Vector,
+};
+
+const MAX_GJK_ITERATIONS: usize = 64;
+const MAX_EPA_ITERATIONS: usize = 1024;
+
+/// Result of [`SupportMap::proximity`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Proximity<T: Numeric> {
+    /// The shapes are apart, `point_a` and `point_b` being the closest
+    /// points on the first and second shape.
+    Disjoint {
+        distance: T,
+        point_a: Vector<T>,
+        point_b: Vector<T>,
+    },
+    /// The shapes overlap or touch.
+    Intersecting,
+}
+
+/// Result of [`SupportMap::penetration`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Penetration<T: Numeric> {
+    /// Length of the shortest translation that separates the shapes. It
+    /// falls short of the exact value by at most
+    /// `T::epsilon().powf(0.25) * depth.max(1)`.
+    pub depth: T,
+    /// Unit direction of that translation when applied to the second shape,
+    /// pointing from the first shape into the second.
+    pub normal: Vector<T>,
+    /// Deepest point of the first shape inside the second one.
+    pub point_a: Vector<T>,
+    /// Deepest point of the second shape inside the first one, equal to
+    /// `point_a - normal * depth`.
+    pub point_b: Vector<T>,
+}
+
+// Point of the Minkowski difference `a - b` together with the support points
+// of both shapes it was built from.
+#[derive(Clone)]
+struct Vertex<T: Numeric> {
+    point: Vector<T>,
+    a: Vector<T>,
+    b: Vector<T>,
+}
+
+fn support<T, A, B>(a: &A, b: &B, direction: &Vector<T>) -> Vertex<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let a = a.support(direction);
+    let b = b.support(&-direction);
+    Vertex {
+        point: &a - &b,
+        a,
+        b,
+    }
+}
+
+fn tolerance<T: Real>() -> T {
+    T::epsilon().sqrt()
+}
+
+// The polytope approximates curved shapes by ever smaller faces, so EPA
+// settles for a looser tolerance than GJK.
+fn epa_tolerance<T: Real>() -> T {
+    tolerance::<T>().sqrt()
+}
+
+enum Outcome<T: Numeric> {
+    Disjoint(Proximity<T>),
+    Intersecting(Vec<Vertex<T>>),
+}
+
+pub(crate) fn proximity<T, A, B>(a: &A, b: &B) -> Proximity<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Outcome::Disjoint(proximity) => proximity,
+        Outcome::Intersecting(_) => Proximity::Intersecting,
+    }
+}
+
+fn gjk<T, A, B>(a: &A, b: &B) -> Outcome<T>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let tolerance = tolerance::<T>();
+    let mut simplex = vec![support(a, b, &Vector::x_axis())];
+    let mut weights = vec![T::one()];
+    for _ in 0..MAX_GJK_ITERATIONS {
+        let (subset, subset_weights) = closest_to_origin(&simplex);
+        simplex = subset.iter().map(|&i| simplex[i].clone()).collect();
+        weights = subset_weights;
+        let v = combine(&simplex, &weights, |vertex| &vertex.point);
+        let v_squared = v.magnitude_squared();
+        let scale = simplex
+            .iter()
+            .map(|vertex| vertex.point.magnitude_squared())
+            .fold(T::zero(), T::max);
+        if simplex.len() == 4 || v_squared <= T::epsilon() * scale {
+            return Outcome::Intersecting(simplex);
+        }
+        let w = support(a, b, &-&v);
+        // No support point gets noticeably closer to the origin than v.
+        let converged = v_squared - v.dot(&w.point) <= tolerance * v_squared;
+        if converged || simplex.iter().any(|vertex| vertex.point == w.point) {
+            break;
+        }
+        simplex.push(w);
+        weights.push(T::zero());
+    }
+    let point_a = combine(&simplex, &weights, |vertex| &vertex.a);
+    let point_b = combine(&simplex, &weights, |vertex| &vertex.b);
+    Outcome::Disjoint(Proximity::Disjoint {
+        distance: (&point_a - &point_b).magnitude(),
+        point_a,
+        point_b,
+    })
+}
+
+fn combine<T: Real>(
+    simplex: &[Vertex<T>],
+    weights: &[T],
+    select: impl Fn(&Vertex<T>) -> &Vector<T>,
+) -> Vector<T> {
+    simplex
+        .iter()
+        .zip(weights)
+        .fold(Vector::default(), |sum, (vertex, &w)| {
+            sum + select(vertex) * w
+        })
+}
+
+// Finds the face of the simplex closest to the origin by checking every
+// sub-simplex whose affine hull contains a closest point with non-negative
+// barycentric weights. Returns the indices of that sub-simplex and the
+// weights of the closest point.
+fn closest_to_origin<T: Real>(simplex: &[Vertex<T>]) -> (Vec<usize>, Vec<T>) {
+    let mut best = (vec![0], vec![T::one()]);
+    let mut best_distance = simplex[0].point.magnitude_squared();
+    for mask in 1..(1usize << simplex.len()) {
+        let subset: Vec<usize> = (0..simplex.len())
+            .filter(|i| mask & (1 << i) != 0)
+            .collect();
+        let Some(weights) = affine_weights(simplex, &subset) else {
+            continue;
+        };
+        if weights.iter().any(|&w| w < T::zero()) {
+            continue;
+        }
+        let points: Vec<Vertex<T>> = subset.iter().map(|&i| simplex[i].clone()).collect();
+        let distance = combine(&points, &weights, |vertex| &vertex.point).magnitude_squared();
+        if distance < best_distance {
+            best = (subset, weights);
+            best_distance = distance;
+        }
+    }
+    best
+}
+
+// Barycentric weights of the point of the affine hull of the subset closest
+// to the origin, from the normal equations of `p0 + sum λi (pi - p0)`.
+// Unused dimensions are padded with the identity so that a single 3x3 solve
+// covers every subset size.
+fn affine_weights<T: Real>(simplex: &[Vertex<T>], subset: &[usize]) -> Option<Vec<T>> {
+    let origin = &simplex[subset[0]].point;
+    let edges: Vec<Vector<T>> = subset[1..]
+        .iter()
+        .map(|&i| &simplex[i].point - origin)
+        .collect();
+    let mut gram = Matrix::unity().to_rows();
+    let mut rhs = [T::zero(); 3];
+    for (i, ei) in edges.iter().enumerate() {
+        for (j, ej) in edges.iter().enumerate() {
+            gram[i][j] = ei.dot(ej);
+        }
+        rhs[i] = -ei.dot(origin);
+    }
+    let diagonal = gram[0][0] * gram[1][1] * gram[2][2];
+    let gram = Matrix::from_rows(gram);
+    if gram.determinant().abs() <= T::epsilon() * diagonal {
+        return None;
+    }
+    let lambda = gram.solve(&Vector::new(rhs[0], rhs[1], rhs[2]))?;
+    let lambda = [lambda.x, lambda.y, lambda.z];
+    let lambda = &lambda[..edges.len()];
+    let first = lambda.iter().fold(T::one(), |rest, &l| rest - l);
+    Some(
+        std::iter::once(first)
+            .chain(lambda.iter().copied())
+            .collect(),
+    )
+}
+
+struct Face<T: Numeric> {
+    indices: [usize; 3],
+    normal: Vector<T>,
+    distance: T,
+}
+
+fn face<T: Real>(vertices: &[Vertex<T>], indices: [usize; 3]) -> Option<Face<T>> {
+    let [i, j, k] = indices;
+    let p = &vertices[i].point;
+    let normal = (&vertices[j].point - p).cross(&(&vertices[k].point - p));
+    if normal.magnitude_squared() == T::zero() {
+        return None;
+    }
+    let normal = normal.normalize();
+    Some(Face {
+        distance: normal.dot(p),
+        indices,
+        normal,
+    })
+}
+
+pub(crate) fn penetration<T, A, B>(a: &A, b: &B) -> Option<Penetration<T>>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let simplex = match gjk(a, b) {
+        Outcome::Intersecting(simplex) => simplex,
+        Outcome::Disjoint(_) => return None,
+    };
+    let mut vertices = tetrahedron(a, b, simplex)?;
+    let mut faces = Vec::new();
+    for (indices, opposite) in [
+        ([0, 1, 2], 3),
+        ([0, 1, 3], 2),
+        ([0, 2, 3], 1),
+        ([1, 2, 3], 0),
+    ] {
+        let mut face = face(&vertices, indices)?;
+        // Orient the normal away from the remaining vertex.
+        if face.normal.dot(&vertices[opposite].point) > face.distance {
+            let [i, j, k] = indices;
+            face = Face {
+                indices: [i, k, j],
+                normal: -face.normal,
+                distance: -face.distance,
+            };
+        }
+        faces.push(face);
+    }
+
+    let tolerance = epa_tolerance::<T>();
+    for _ in 0..MAX_EPA_ITERATIONS {
+        let closest = faces
+            .iter()
+            .enumerate()
+            .min_by(|(_, f), (_, g)| {
+                f.distance
+                    .partial_cmp(&g.distance)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, _)| index)?;
+        let w = support(a, b, &faces[closest].normal);
+        let gain = w.point.dot(&faces[closest].normal) - faces[closest].distance;
+        if gain <= tolerance * faces[closest].distance.abs().max(T::one()) {
+            return Some(contact(&vertices, &faces[closest]));
+        }
+
+        // Replace every face w can see by a fan of faces around the horizon.
+        let index = vertices.len();
+        vertices.push(w);
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            let p = &vertices[face.indices[0]].point;
+            if face.normal.dot(&(&vertices[index].point - p)) <= T::zero() {
+                return true;
+            }
+            let [i, j, k] = face.indices;
+            for edge in [(i, j), (j, k), (k, i)] {
+                match horizon.iter().position(|&e| e == (edge.1, edge.0)) {
+                    Some(shared) => {
+                        horizon.swap_remove(shared);
+                    }
+                    None => horizon.push(edge),
+                }
+            }
+            false
+        });
+        for (i, j) in horizon {
+            faces.push(face(&vertices, [i, j, index])?);
+        }
+    }
+    // Not converged, the closest face may still be far from the true depth.
+    None
+}
+
+fn contact<T: Real>(vertices: &[Vertex<T>], face: &Face<T>) -> Penetration<T> {
+    let [i, j, k] = face.indices.map(|i| &vertices[i]);
+    let triangle = Triangle::new(i.point.clone(), j.point.clone(), k.point.clone());
+    let projection = &face.normal * face.distance;
+    let weights = triangle
+        .barycentric(&projection)
+        .unwrap_or_else(|| Vector::new(T::one(), T::zero(), T::zero()));
+    let point_a = &i.a * weights.x + &j.a * weights.y + &k.a * weights.z;
+    let point_b = &i.b * weights.x + &j.b * weights.y + &k.b * weights.z;
+    Penetration {
+        depth: face.distance,
+        normal: face.normal.clone(),
+        point_a,
+        point_b,
+    }
+}
+
+// Grows the final GJK simplex into a tetrahedron of non-zero volume, which
+// is needed when the shapes merely touch. Returns `None` if the Minkowski
+// difference is flat.
+fn tetrahedron<T, A, B>(a: &A, b: &B, mut simplex: Vec<Vertex<T>>) -> Option<Vec<Vertex<T>>>
+where
+    T: Real,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let directions = [
+        Vector::x_axis(),
+        -Vector::x_axis(),
+        Vector::y_axis(),
+        -Vector::y_axis(),
+        Vector::z_axis(),
+        -Vector::z_axis(),
+    ];
+    let scale = directions
+        .iter()
+        .map(|d| support(a, b, d).point.magnitude())
+        .fold(T::zero(), T::max);
+    let threshold = tolerance::<T>() * scale;
+
+    if simplex.len() == 1 {
+        let w = directions
+            .iter()
+            .map(|d| support(a, b, d))
+            .find(|w| (&w.point - &simplex[0].point).magnitude() > threshold)?;
+        simplex.push(w);
+    }
+    if simplex.len() == 2 {
+        let axis = (&simplex[1].point - &simplex[0].point).normalize();
+        let perpendicular = axis.orthogonal().normalize();
+        let w = (0..6)
+            .map(|i| {
+                let angle = T::pi() * T::from_f64(i as f64) / T::from_f64(3.0);
+                support(a, b, &(Matrix::rotation(angle, &axis) * &perpendicular))
+            })
+            .find(|w| (&w.point - &simplex[0].point).cross(&axis).magnitude() > threshold)?;
+        simplex.push(w);
+    }
+    if simplex.len() == 3 {
+        let p = &simplex[0].point;
+        let normal = (&simplex[1].point - p)
+            .cross(&(&simplex[2].point - p))
+            .normalize();
+        let w = [normal.clone(), -normal.clone()]
+            .iter()
+            .map(|d| support(a, b, d))
+            .find(|w| (&w.point - p).dot(&normal).abs() > threshold)?;
+        simplex.push(w);
+    }
+    let p = &simplex[0].point;
+    let volume = (&simplex[1].point - p)
+        .cross(&(&simplex[2].point - p))
+        .dot(&(&simplex[3].point - p));
+    if volume.abs() <= threshold * threshold * threshold {
+        return None;
+    }
+    Some(simplex)
+}
@@ -1,46 +1,90 @@
+mod aabb;
+mod dual_quaternion;
+mod eigen;
+mod euler;
+mod frustum;
+mod gjk;
+mod line;
 mod matrix;
+mod matrix4;
 mod numeric;
+mod obb;
+mod plane;
+mod projection;
+mod quaternion;
+mod ray;
+mod solve;
+mod sphere;
+mod support;
+mod svd;
+mod transform;
+mod triangle;
 mod vector;
+mod vector2;
+mod vector4;
+mod view;
 
 use std::ops::Mul;
 
+pub use aabb::Aabb;
+pub use dual_quaternion::DualQuaternion;
+pub use eigen::SymmetricEigen;
+pub use euler::{EulerAngles, EulerFrame, EulerOrder};
+pub use frustum::{Containment, Frustum};
+pub use gjk::{Penetration, Proximity};
+pub use line::{Line, Segment};
 pub use matrix::Matrix;
-pub use numeric::Numeric;
+pub use matrix4::Matrix4;
+pub use numeric::{Checked, Numeric, Real, Signed};
+pub use obb::Obb;
+pub use plane::Plane;
+pub use projection::{DepthRange, Handedness};
+pub use quaternion::Quaternion;
+pub use ray::{Ray, RayHit, TriangleHit};
+pub use solve::{InverseError, Lu, Qr};
+pub use sphere::Sphere;
+pub use support::{Capsule, Cone, ConvexHull, Cylinder, SupportMap};
+pub use svd::Svd;
+pub use transform::{Isometry, Transform};
+pub use triangle::Triangle;
 pub use vector::Vector;
+pub use vector2::Vector2;
+pub use vector4::Vector4;
 
-impl<T: Numeric + Into<f32> + From<f32>> Vector<T> {
-    pub fn rotate_around(&self, radians: f32, axis: &Vector<T>) -> Vector<T> {
+impl<T: Real> Vector<T> {
+    pub fn rotate_around(&self, radians: T, axis: &Vector<T>) -> Vector<T> {
         let matrix = Matrix::rotation(radians, axis);
         matrix * self
     }
-    pub fn rotate_degree_around(&self, degree: f32, axis: &Vector<T>) -> Vector<T> {
+    pub fn rotate_degree_around(&self, degree: T, axis: &Vector<T>) -> Vector<T> {
         let matrix = Matrix::rotation_degree(degree, axis);
         matrix * self
     }
 }
-impl<T: Numeric + Into<f32> + From<f32>> Matrix<T> {
-    pub fn rotation(radians: f32, axis: &Vector<T>) -> Self {
-        let x = axis.x.into();
-        let y = axis.y.into();
-        let z = axis.z.into();
+impl<T: Real> Matrix<T> {
+    /// Rotation by `radians` around `axis`, which has to be of unit length.
+    pub fn rotation(radians: T, axis: &Vector<T>) -> Self {
+        let x = axis.x;
+        let y = axis.y;
+        let z = axis.z;
         let c = radians.cos();
         let s = radians.sin();
+        let t = T::one() - c;
         Self::new(
-            (x.powi(2) * (1.0 - c) + c).into(),
-            (x * y * (1.0 - c) - z * s).into(),
-            (x * z * (1.0 - c) + y * s).into(),
-            (y * x * (1.0 - c) + z * s).into(),
-            (y.powi(2) * (1.0 - c) + c).into(),
-            (y * z * (1.0 - c) - x * s).into(),
-            (z * x * (1.0 - c) - y * s).into(),
-            (z * y * (1.0 - c) + x * s).into(),
-            (z.powi(2) * (1.0 - c) + c).into(),
+            x * x * t + c,
+            x * y * t - z * s,
+            x * z * t + y * s,
+            y * x * t + z * s,
+            y * y * t + c,
+            y * z * t - x * s,
+            z * x * t - y * s,
+            z * y * t + x * s,
+            z * z * t + c,
         )
     }
 
-    pub fn rotation_degree(degree: f32, axis: &Vector<T>) -> Self {
-        let radians = degree * std::f32::consts::PI / 180.0;
-        Self::rotation(radians, axis)
+    pub fn rotation_degree(degree: T, axis: &Vector<T>) -> Self {
+        Self::rotation(degree.to_radians(), axis)
     }
 }
 
@@ -50,8 +94,8 @@ impl<T: Numeric> Mul<Vector<T>> for Matrix<T> {
     fn mul(self, rhs: Vector<T>) -> Self::Output {
         Vector::new(
             self.m11 * rhs.x + self.m12 * rhs.y + self.m13 * rhs.z,
-            self.m12 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
-            self.m13 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
+            self.m21 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
+            self.m31 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
         )
     }
 }
@@ -61,8 +105,8 @@ impl<T: Numeric> Mul<&Vector<T>> for Matrix<T> {
     fn mul(self, rhs: &Vector<T>) -> Self::Output {
         Vector::new(
             self.m11 * rhs.x + self.m12 * rhs.y + self.m13 * rhs.z,
-            self.m12 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
-            self.m13 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
+            self.m21 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
+            self.m31 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
         )
     }
 }
@@ -72,8 +116,8 @@ impl<T: Numeric> Mul<Vector<T>> for &Matrix<T> {
     fn mul(self, rhs: Vector<T>) -> Self::Output {
         Vector::new(
             self.m11 * rhs.x + self.m12 * rhs.y + self.m13 * rhs.z,
-            self.m12 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
-            self.m13 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
+            self.m21 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
+            self.m31 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
         )
     }
 }
@@ -83,8 +127,54 @@ impl<T: Numeric> Mul<&Vector<T>> for &Matrix<T> {
     fn mul(self, rhs: &Vector<T>) -> Self::Output {
         Vector::new(
             self.m11 * rhs.x + self.m12 * rhs.y + self.m13 * rhs.z,
-            self.m12 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
-            self.m13 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
+            self.m21 * rhs.x + self.m22 * rhs.y + self.m23 * rhs.z,
+            self.m31 * rhs.x + self.m32 * rhs.y + self.m33 * rhs.z,
+        )
+    }
+}
+
+// Row-vector product `v^T * M`, equivalent to `M^T * v`.
+impl<T: Numeric> Mul<Matrix<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        Vector::new(
+            self.x * rhs.m11 + self.y * rhs.m21 + self.z * rhs.m31,
+            self.x * rhs.m12 + self.y * rhs.m22 + self.z * rhs.m32,
+            self.x * rhs.m13 + self.y * rhs.m23 + self.z * rhs.m33,
+        )
+    }
+}
+impl<T: Numeric> Mul<&Matrix<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        Vector::new(
+            self.x * rhs.m11 + self.y * rhs.m21 + self.z * rhs.m31,
+            self.x * rhs.m12 + self.y * rhs.m22 + self.z * rhs.m32,
+            self.x * rhs.m13 + self.y * rhs.m23 + self.z * rhs.m33,
+        )
+    }
+}
+impl<T: Numeric> Mul<Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        Vector::new(
+            self.x * rhs.m11 + self.y * rhs.m21 + self.z * rhs.m31,
+            self.x * rhs.m12 + self.y * rhs.m22 + self.z * rhs.m32,
+            self.x * rhs.m13 + self.y * rhs.m23 + self.z * rhs.m33,
+        )
+    }
+}
+impl<T: Numeric> Mul<&Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        Vector::new(
+            self.x * rhs.m11 + self.y * rhs.m21 + self.z * rhs.m31,
+            self.x * rhs.m12 + self.y * rhs.m22 + self.z * rhs.m32,
+            self.x * rhs.m13 + self.y * rhs.m23 + self.z * rhs.m33,
         )
     }
 }
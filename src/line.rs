@@ -0,0 +1,185 @@
+use crate::{
+    numeric::{Numeric, Real},
+    ray::Ray,
+    triangle::Triangle,
+    vector::Vector,
+};
+
+/// Infinite line through `point` along the non-zero `direction`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line<T: Numeric> {
+    pub point: Vector<T>,
+    pub direction: Vector<T>,
+}
+
+/// Line segment from `start` to `end`, both inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment<T: Numeric> {
+    pub start: Vector<T>,
+    pub end: Vector<T>,
+}
+
+impl<T: Numeric> Line<T> {
+    pub fn new(point: Vector<T>, direction: Vector<T>) -> Self {
+        Self { point, direction }
+    }
+
+    pub fn at(&self, t: T) -> Vector<T> {
+        &self.point + &self.direction * t
+    }
+}
+impl<T: Real> Line<T> {
+    pub fn closest_point(&self, point: &Vector<T>) -> Vector<T> {
+        let t = (point - &self.point).dot(&self.direction) / self.direction.magnitude_squared();
+        self.at(t)
+    }
+
+    pub fn distance_squared(&self, point: &Vector<T>) -> T {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    pub fn distance(&self, point: &Vector<T>) -> T {
+        self.distance_squared(point).sqrt()
+    }
+
+    /// Shortest segment from this line to `rhs`, starting on this line. For
+    /// parallel lines, where every connector is equally short, it starts at
+    /// `self.point`.
+    pub fn shortest_connector(&self, rhs: &Self) -> Segment<T> {
+        let w = &self.point - &rhs.point;
+        let a = self.direction.magnitude_squared();
+        let b = self.direction.dot(&rhs.direction);
+        let c = rhs.direction.magnitude_squared();
+        let d = self.direction.dot(&w);
+        let e = rhs.direction.dot(&w);
+        let denominator = a * c - b * b;
+        let (s, t) = if denominator <= T::epsilon() * a * c {
+            (T::zero(), e / c)
+        } else {
+            ((b * e - c * d) / denominator, (a * e - b * d) / denominator)
+        };
+        Segment::new(self.at(s), rhs.at(t))
+    }
+}
+
+impl<T: Numeric> Segment<T> {
+    pub fn new(start: Vector<T>, end: Vector<T>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn direction(&self) -> Vector<T> {
+        &self.end - &self.start
+    }
+
+    pub fn at(&self, t: T) -> Vector<T> {
+        &self.start + self.direction() * t
+    }
+}
+impl<T: Real> Segment<T> {
+    pub fn length(&self) -> T {
+        self.direction().magnitude()
+    }
+
+    pub fn closest_point(&self, point: &Vector<T>) -> Vector<T> {
+        let direction = self.direction();
+        let length_squared = direction.magnitude_squared();
+        if length_squared == T::zero() {
+            return self.start.clone();
+        }
+        let t = (point - &self.start).dot(&direction) / length_squared;
+        self.at(t.clamp(T::zero(), T::one()))
+    }
+
+    pub fn distance_squared(&self, point: &Vector<T>) -> T {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    pub fn distance(&self, point: &Vector<T>) -> T {
+        self.distance_squared(point).sqrt()
+    }
+
+    /// Closest points on this segment and on `rhs` (Ericson, "Real-Time
+    /// Collision Detection", 5.1.9). Degenerate segments are treated as
+    /// points; for parallel segments one of the equally close pairs is
+    /// returned.
+    pub fn closest_points(&self, rhs: &Self) -> (Vector<T>, Vector<T>) {
+        let (zero, one) = (T::zero(), T::one());
+        let d1 = self.direction();
+        let d2 = rhs.direction();
+        let r = &self.start - &rhs.start;
+        let a = d1.magnitude_squared();
+        let e = d2.magnitude_squared();
+        let f = d2.dot(&r);
+        let (s, t) = if a == zero && e == zero {
+            (zero, zero)
+        } else if a == zero {
+            (zero, (f / e).clamp(zero, one))
+        } else {
+            let c = d1.dot(&r);
+            if e == zero {
+                ((-c / a).clamp(zero, one), zero)
+            } else {
+                let b = d1.dot(&d2);
+                let denominator = a * e - b * b;
+                // Parallel segments have no unique closest pair, any s works.
+                let s = if denominator > T::epsilon() * a * e {
+                    ((b * f - c * e) / denominator).clamp(zero, one)
+                } else {
+                    zero
+                };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    ((-c / a).clamp(zero, one), zero)
+                } else if t > one {
+                    (((b - c) / a).clamp(zero, one), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), rhs.at(t))
+    }
+
+    pub fn distance_to_segment(&self, rhs: &Self) -> T {
+        let (p, q) = self.closest_points(rhs);
+        (p - q).magnitude()
+    }
+
+    /// Closest points on this segment and on `triangle`. If the segment
+    /// pierces the triangle both points are the intersection point.
+    pub fn closest_points_to_triangle(&self, triangle: &Triangle<T>) -> (Vector<T>, Vector<T>) {
+        let ray = Ray::new(self.start.clone(), self.direction());
+        if let Some(hit) = ray.intersect_triangle(&triangle.a, &triangle.b, &triangle.c, false) {
+            if hit.hit.distance <= T::one() {
+                return (hit.hit.point.clone(), hit.hit.point);
+            }
+        }
+        // Otherwise the minimum is attained on an edge of the triangle or at
+        // an endpoint of the segment.
+        let edges = [
+            Segment::new(triangle.a.clone(), triangle.b.clone()),
+            Segment::new(triangle.b.clone(), triangle.c.clone()),
+            Segment::new(triangle.c.clone(), triangle.a.clone()),
+        ];
+        let mut closest = (self.start.clone(), triangle.closest_point(&self.start));
+        let mut distance = (&closest.0 - &closest.1).magnitude_squared();
+        let end = (self.end.clone(), triangle.closest_point(&self.end));
+        for (p, q) in edges
+            .iter()
+            .map(|edge| self.closest_points(edge))
+            .chain([end])
+        {
+            let candidate = (&p - &q).magnitude_squared();
+            if candidate < distance {
+                closest = (p, q);
+                distance = candidate;
+            }
+        }
+        closest
+    }
+
+    pub fn distance_to_triangle(&self, triangle: &Triangle<T>) -> T {
+        let (p, q) = self.closest_points_to_triangle(triangle);
+        (p - q).magnitude()
+    }
+}
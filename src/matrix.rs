@@ -1,7 +1,17 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
 
-use crate::numeric::Numeric;
+use crate::{
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
 
+/// A 3x3 matrix stored row-major, `mRC` being the entry in row `R` and
+/// column `C`. Vectors are treated as columns, so `matrix * vector` applies
+/// the transform and `a * b` applies `b` first.
+#[derive(PartialEq, Eq)]
 pub struct Matrix<T: Numeric> {
     pub m11: T,
     pub m12: T,
@@ -29,6 +39,7 @@ impl<T: Numeric> Default for Matrix<T> {
     }
 }
 impl<T: Numeric> Matrix<T> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(m11: T, m12: T, m13: T, m21: T, m22: T, m23: T, m31: T, m32: T, m33: T) -> Self {
         Self {
             m11,
@@ -76,27 +87,66 @@ impl<T: Numeric> Matrix<T> {
             m33: self.m33,
         }
     }
-}
-impl<T: Numeric + Into<f32> + From<f32>> Matrix<T> {
+
+    pub fn from_rows(rows: [[T; 3]; 3]) -> Self {
+        let [[m11, m12, m13], [m21, m22, m23], [m31, m32, m33]] = rows;
+        Self::new(m11, m12, m13, m21, m22, m23, m31, m32, m33)
+    }
+
+    pub fn to_rows(&self) -> [[T; 3]; 3] {
+        [
+            [self.m11, self.m12, self.m13],
+            [self.m21, self.m22, self.m23],
+            [self.m31, self.m32, self.m33],
+        ]
+    }
+
+    pub fn from_columns(c1: &Vector<T>, c2: &Vector<T>, c3: &Vector<T>) -> Self {
+        Self::new(c1.x, c2.x, c3.x, c1.y, c2.y, c3.y, c1.z, c2.z, c3.z)
+    }
+
+    pub fn row(&self, index: usize) -> Vector<T> {
+        match index {
+            0 => Vector::new(self.m11, self.m12, self.m13),
+            1 => Vector::new(self.m21, self.m22, self.m23),
+            2 => Vector::new(self.m31, self.m32, self.m33),
+            _ => panic!("row index {index} out of range for a 3x3 matrix"),
+        }
+    }
+
+    pub fn column(&self, index: usize) -> Vector<T> {
+        match index {
+            0 => Vector::new(self.m11, self.m21, self.m31),
+            1 => Vector::new(self.m12, self.m22, self.m32),
+            2 => Vector::new(self.m13, self.m23, self.m33),
+            _ => panic!("column index {index} out of range for a 3x3 matrix"),
+        }
+    }
+
     pub fn unity() -> Self {
         Self {
-            m11: T::from(1.0),
-            m12: T::from(0.0),
-            m13: T::from(0.0),
-            m21: T::from(0.0),
-            m22: T::from(1.0),
-            m23: T::from(0.0),
-            m31: T::from(0.0),
-            m32: T::from(0.0),
-            m33: T::from(1.0),
+            m11: T::one(),
+            m12: T::zero(),
+            m13: T::zero(),
+            m21: T::zero(),
+            m22: T::one(),
+            m23: T::zero(),
+            m31: T::zero(),
+            m32: T::zero(),
+            m33: T::one(),
         }
     }
+}
+impl<T: Real> Matrix<T> {
+    /// Inverse via the adjugate. Only an exactly zero determinant is
+    /// rejected, nearly singular matrices yield huge entries. Use
+    /// [`Matrix::try_inverse`] to reject ill-conditioned input.
     pub fn inverse(&self) -> Option<Matrix<T>> {
-        let det = self.determinant().into();
-        if det == 0.0 {
+        let det = self.determinant();
+        if det == T::zero() {
             return None;
         }
-        let inv_det = (1.0 / det).into();
+        let inv_det = T::one() / det;
         Some(Self {
             m11: (self.m22 * self.m33 - self.m23 * self.m32) * inv_det,
             m12: (self.m13 * self.m32 - self.m12 * self.m33) * inv_det,
@@ -111,6 +161,31 @@ impl<T: Numeric + Into<f32> + From<f32>> Matrix<T> {
     }
 }
 
+impl<T: Numeric + Debug> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Matrix")
+            .field("m11", &self.m11)
+            .field("m12", &self.m12)
+            .field("m13", &self.m13)
+            .field("m21", &self.m21)
+            .field("m22", &self.m22)
+            .field("m23", &self.m23)
+            .field("m31", &self.m31)
+            .field("m32", &self.m32)
+            .field("m33", &self.m33)
+            .finish()
+    }
+}
+
+impl<T: Numeric + Clone> Clone for Matrix<T> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.m11, self.m12, self.m13, self.m21, self.m22, self.m23, self.m31, self.m32,
+            self.m33,
+        )
+    }
+}
+
 impl<T: Numeric> Mul for Matrix<T> {
     type Output = Self;
 
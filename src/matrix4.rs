@@ -0,0 +1,600 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// A homogeneous 4x4 matrix following the same row-major, column-vector
+/// convention as [`Matrix`]; the translation lives in `m14`, `m24`, `m34`.
+#[derive(PartialEq, Eq)]
+pub struct Matrix4<T: Numeric> {
+    pub m11: T,
+    pub m12: T,
+    pub m13: T,
+    pub m14: T,
+    pub m21: T,
+    pub m22: T,
+    pub m23: T,
+    pub m24: T,
+    pub m31: T,
+    pub m32: T,
+    pub m33: T,
+    pub m34: T,
+    pub m41: T,
+    pub m42: T,
+    pub m43: T,
+    pub m44: T,
+}
+impl<T: Numeric> Default for Matrix4<T> {
+    fn default() -> Self {
+        Self {
+            m11: T::default(),
+            m12: T::default(),
+            m13: T::default(),
+            m14: T::default(),
+            m21: T::default(),
+            m22: T::default(),
+            m23: T::default(),
+            m24: T::default(),
+            m31: T::default(),
+            m32: T::default(),
+            m33: T::default(),
+            m34: T::default(),
+            m41: T::default(),
+            m42: T::default(),
+            m43: T::default(),
+            m44: T::default(),
+        }
+    }
+}
+impl<T: Numeric> Matrix4<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        m11: T,
+        m12: T,
+        m13: T,
+        m14: T,
+        m21: T,
+        m22: T,
+        m23: T,
+        m24: T,
+        m31: T,
+        m32: T,
+        m33: T,
+        m34: T,
+        m41: T,
+        m42: T,
+        m43: T,
+        m44: T,
+    ) -> Self {
+        Self {
+            m11,
+            m12,
+            m13,
+            m14,
+            m21,
+            m22,
+            m23,
+            m24,
+            m31,
+            m32,
+            m33,
+            m34,
+            m41,
+            m42,
+            m43,
+            m44,
+        }
+    }
+
+    pub fn scalar(scalar: T) -> Self {
+        Self {
+            m11: scalar,
+            m12: scalar,
+            m13: scalar,
+            m14: scalar,
+            m21: scalar,
+            m22: scalar,
+            m23: scalar,
+            m24: scalar,
+            m31: scalar,
+            m32: scalar,
+            m33: scalar,
+            m34: scalar,
+            m41: scalar,
+            m42: scalar,
+            m43: scalar,
+            m44: scalar,
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            m11: self.m11,
+            m12: self.m21,
+            m13: self.m31,
+            m14: self.m41,
+            m21: self.m12,
+            m22: self.m22,
+            m23: self.m32,
+            m24: self.m42,
+            m31: self.m13,
+            m32: self.m23,
+            m33: self.m33,
+            m34: self.m43,
+            m41: self.m14,
+            m42: self.m24,
+            m43: self.m34,
+            m44: self.m44,
+        }
+    }
+
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    fn minors(&self) -> ([T; 6], [T; 6]) {
+        let s = [
+            self.m11 * self.m22 - self.m21 * self.m12,
+            self.m11 * self.m23 - self.m21 * self.m13,
+            self.m11 * self.m24 - self.m21 * self.m14,
+            self.m12 * self.m23 - self.m22 * self.m13,
+            self.m12 * self.m24 - self.m22 * self.m14,
+            self.m13 * self.m24 - self.m23 * self.m14,
+        ];
+        let c = [
+            self.m31 * self.m42 - self.m41 * self.m32,
+            self.m31 * self.m43 - self.m41 * self.m33,
+            self.m31 * self.m44 - self.m41 * self.m34,
+            self.m32 * self.m43 - self.m42 * self.m33,
+            self.m32 * self.m44 - self.m42 * self.m34,
+            self.m33 * self.m44 - self.m43 * self.m34,
+        ];
+        (s, c)
+    }
+
+    /// Applies the matrix to `v` as a direction, i.e. with an implicit w of 0.
+    pub fn transform_vector(&self, v: &Vector<T>) -> Vector<T> {
+        Vector::new(
+            self.m11 * v.x + self.m12 * v.y + self.m13 * v.z,
+            self.m21 * v.x + self.m22 * v.y + self.m23 * v.z,
+            self.m31 * v.x + self.m32 * v.y + self.m33 * v.z,
+        )
+    }
+
+    pub fn unity() -> Self {
+        let one = T::one();
+        let zero = T::zero();
+        Self::new(
+            one, zero, zero, zero, zero, one, zero, zero, zero, zero, one, zero, zero, zero, zero,
+            one,
+        )
+    }
+
+    pub fn translation(translation: &Vector<T>) -> Self {
+        let mut m = Self::unity();
+        m.m14 = translation.x;
+        m.m24 = translation.y;
+        m.m34 = translation.z;
+        m
+    }
+
+    pub fn scaling(scale: &Vector<T>) -> Self {
+        let mut m = Self::unity();
+        m.m11 = scale.x;
+        m.m22 = scale.y;
+        m.m33 = scale.z;
+        m
+    }
+
+    /// Applies the matrix to `p` as a point, i.e. with an implicit w of 1.
+    /// Projective results are divided by the resulting w. Returns `None` if
+    /// w is zero, i.e. `p` maps to a point at infinity, which happens for
+    /// points on the eye plane of a perspective projection.
+    pub fn transform_point(&self, p: &Vector<T>) -> Option<Vector<T>> {
+        let x = self.m11 * p.x + self.m12 * p.y + self.m13 * p.z + self.m14;
+        let y = self.m21 * p.x + self.m22 * p.y + self.m23 * p.z + self.m24;
+        let z = self.m31 * p.x + self.m32 * p.y + self.m33 * p.z + self.m34;
+        let w = self.m41 * p.x + self.m42 * p.y + self.m43 * p.z + self.m44;
+        if w == T::zero() {
+            None
+        } else if w == T::one() {
+            Some(Vector::new(x, y, z))
+        } else {
+            Some(Vector::new(x / w, y / w, z / w))
+        }
+    }
+}
+impl<T: Real> Matrix4<T> {
+    pub fn rotation(radians: T, axis: &Vector<T>) -> Self {
+        Self::from(Matrix::rotation(radians, axis))
+    }
+
+    pub fn rotation_degree(degree: T, axis: &Vector<T>) -> Self {
+        Self::from(Matrix::rotation_degree(degree, axis))
+    }
+
+    pub fn inverse(&self) -> Option<Matrix4<T>> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let inv_det = T::one() / det;
+        let (s, c) = self.minors();
+        Some(Self {
+            m11: (self.m22 * c[5] - self.m23 * c[4] + self.m24 * c[3]) * inv_det,
+            m12: (self.m13 * c[4] - self.m12 * c[5] - self.m14 * c[3]) * inv_det,
+            m13: (self.m42 * s[5] - self.m43 * s[4] + self.m44 * s[3]) * inv_det,
+            m14: (self.m33 * s[4] - self.m32 * s[5] - self.m34 * s[3]) * inv_det,
+            m21: (self.m23 * c[2] - self.m21 * c[5] - self.m24 * c[1]) * inv_det,
+            m22: (self.m11 * c[5] - self.m13 * c[2] + self.m14 * c[1]) * inv_det,
+            m23: (self.m43 * s[2] - self.m41 * s[5] - self.m44 * s[1]) * inv_det,
+            m24: (self.m31 * s[5] - self.m33 * s[2] + self.m34 * s[1]) * inv_det,
+            m31: (self.m21 * c[4] - self.m22 * c[2] + self.m24 * c[0]) * inv_det,
+            m32: (self.m12 * c[2] - self.m11 * c[4] - self.m14 * c[0]) * inv_det,
+            m33: (self.m41 * s[4] - self.m42 * s[2] + self.m44 * s[0]) * inv_det,
+            m34: (self.m32 * s[2] - self.m31 * s[4] - self.m34 * s[0]) * inv_det,
+            m41: (self.m22 * c[1] - self.m21 * c[3] - self.m23 * c[0]) * inv_det,
+            m42: (self.m11 * c[3] - self.m12 * c[1] + self.m13 * c[0]) * inv_det,
+            m43: (self.m42 * s[1] - self.m41 * s[3] - self.m43 * s[0]) * inv_det,
+            m44: (self.m31 * s[3] - self.m32 * s[1] + self.m33 * s[0]) * inv_det,
+        })
+    }
+}
+
+impl<T: Numeric> From<&Matrix<T>> for Matrix4<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        let one = T::one();
+        let zero = T::zero();
+        Self::new(
+            m.m11, m.m12, m.m13, zero, m.m21, m.m22, m.m23, zero, m.m31, m.m32, m.m33, zero, zero,
+            zero, zero, one,
+        )
+    }
+}
+impl<T: Numeric> From<Matrix<T>> for Matrix4<T> {
+    fn from(m: Matrix<T>) -> Self {
+        Self::from(&m)
+    }
+}
+impl<T: Numeric> From<&Matrix4<T>> for Matrix<T> {
+    fn from(m: &Matrix4<T>) -> Self {
+        Matrix::new(
+            m.m11, m.m12, m.m13, m.m21, m.m22, m.m23, m.m31, m.m32, m.m33,
+        )
+    }
+}
+impl<T: Numeric> From<Matrix4<T>> for Matrix<T> {
+    fn from(m: Matrix4<T>) -> Self {
+        Self::from(&m)
+    }
+}
+
+impl<T: Numeric + Debug> Debug for Matrix4<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Matrix4")
+            .field("m11", &self.m11)
+            .field("m12", &self.m12)
+            .field("m13", &self.m13)
+            .field("m14", &self.m14)
+            .field("m21", &self.m21)
+            .field("m22", &self.m22)
+            .field("m23", &self.m23)
+            .field("m24", &self.m24)
+            .field("m31", &self.m31)
+            .field("m32", &self.m32)
+            .field("m33", &self.m33)
+            .field("m34", &self.m34)
+            .field("m41", &self.m41)
+            .field("m42", &self.m42)
+            .field("m43", &self.m43)
+            .field("m44", &self.m44)
+            .finish()
+    }
+}
+
+impl<T: Numeric + Clone> Clone for Matrix4<T> {
+    fn clone(&self) -> Self {
+        Self {
+            m11: self.m11,
+            m12: self.m12,
+            m13: self.m13,
+            m14: self.m14,
+            m21: self.m21,
+            m22: self.m22,
+            m23: self.m23,
+            m24: self.m24,
+            m31: self.m31,
+            m32: self.m32,
+            m33: self.m33,
+            m34: self.m34,
+            m41: self.m41,
+            m42: self.m42,
+            m43: self.m43,
+            m44: self.m44,
+        }
+    }
+}
+
+impl<T: Numeric> Mul for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+impl<T: Numeric> Mul<&Matrix4<T>> for Matrix4<T> {
+    type Output = Matrix4<T>;
+
+    fn mul(self, rhs: &Matrix4<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+impl<T: Numeric> Mul for &Matrix4<T> {
+    type Output = Matrix4<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Matrix4 {
+            m11: self.m11 * rhs.m11 + self.m12 * rhs.m21 + self.m13 * rhs.m31 + self.m14 * rhs.m41,
+            m12: self.m11 * rhs.m12 + self.m12 * rhs.m22 + self.m13 * rhs.m32 + self.m14 * rhs.m42,
+            m13: self.m11 * rhs.m13 + self.m12 * rhs.m23 + self.m13 * rhs.m33 + self.m14 * rhs.m43,
+            m14: self.m11 * rhs.m14 + self.m12 * rhs.m24 + self.m13 * rhs.m34 + self.m14 * rhs.m44,
+            m21: self.m21 * rhs.m11 + self.m22 * rhs.m21 + self.m23 * rhs.m31 + self.m24 * rhs.m41,
+            m22: self.m21 * rhs.m12 + self.m22 * rhs.m22 + self.m23 * rhs.m32 + self.m24 * rhs.m42,
+            m23: self.m21 * rhs.m13 + self.m22 * rhs.m23 + self.m23 * rhs.m33 + self.m24 * rhs.m43,
+            m24: self.m21 * rhs.m14 + self.m22 * rhs.m24 + self.m23 * rhs.m34 + self.m24 * rhs.m44,
+            m31: self.m31 * rhs.m11 + self.m32 * rhs.m21 + self.m33 * rhs.m31 + self.m34 * rhs.m41,
+            m32: self.m31 * rhs.m12 + self.m32 * rhs.m22 + self.m33 * rhs.m32 + self.m34 * rhs.m42,
+            m33: self.m31 * rhs.m13 + self.m32 * rhs.m23 + self.m33 * rhs.m33 + self.m34 * rhs.m43,
+            m34: self.m31 * rhs.m14 + self.m32 * rhs.m24 + self.m33 * rhs.m34 + self.m34 * rhs.m44,
+            m41: self.m41 * rhs.m11 + self.m42 * rhs.m21 + self.m43 * rhs.m31 + self.m44 * rhs.m41,
+            m42: self.m41 * rhs.m12 + self.m42 * rhs.m22 + self.m43 * rhs.m32 + self.m44 * rhs.m42,
+            m43: self.m41 * rhs.m13 + self.m42 * rhs.m23 + self.m43 * rhs.m33 + self.m44 * rhs.m43,
+            m44: self.m41 * rhs.m14 + self.m42 * rhs.m24 + self.m43 * rhs.m34 + self.m44 * rhs.m44,
+        }
+    }
+}
+impl<T: Numeric> Mul<Matrix4<T>> for &Matrix4<T> {
+    type Output = Matrix4<T>;
+
+    fn mul(self, rhs: Matrix4<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+impl<T: Numeric> MulAssign for Matrix4<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * rhs;
+    }
+}
+impl<T: Numeric> MulAssign<&Matrix4<T>> for Matrix4<T> {
+    fn mul_assign(&mut self, rhs: &Matrix4<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+macro_rules! impl_matrix4_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op for Matrix4<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self {
+                    m11: self.m11.$method(rhs.m11),
+                    m12: self.m12.$method(rhs.m12),
+                    m13: self.m13.$method(rhs.m13),
+                    m14: self.m14.$method(rhs.m14),
+                    m21: self.m21.$method(rhs.m21),
+                    m22: self.m22.$method(rhs.m22),
+                    m23: self.m23.$method(rhs.m23),
+                    m24: self.m24.$method(rhs.m24),
+                    m31: self.m31.$method(rhs.m31),
+                    m32: self.m32.$method(rhs.m32),
+                    m33: self.m33.$method(rhs.m33),
+                    m34: self.m34.$method(rhs.m34),
+                    m41: self.m41.$method(rhs.m41),
+                    m42: self.m42.$method(rhs.m42),
+                    m43: self.m43.$method(rhs.m43),
+                    m44: self.m44.$method(rhs.m44),
+                }
+            }
+        }
+        impl<T: Numeric> $op<&Matrix4<T>> for Matrix4<T> {
+            type Output = Matrix4<T>;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                Matrix4 {
+                    m11: self.m11.$method(rhs.m11),
+                    m12: self.m12.$method(rhs.m12),
+                    m13: self.m13.$method(rhs.m13),
+                    m14: self.m14.$method(rhs.m14),
+                    m21: self.m21.$method(rhs.m21),
+                    m22: self.m22.$method(rhs.m22),
+                    m23: self.m23.$method(rhs.m23),
+                    m24: self.m24.$method(rhs.m24),
+                    m31: self.m31.$method(rhs.m31),
+                    m32: self.m32.$method(rhs.m32),
+                    m33: self.m33.$method(rhs.m33),
+                    m34: self.m34.$method(rhs.m34),
+                    m41: self.m41.$method(rhs.m41),
+                    m42: self.m42.$method(rhs.m42),
+                    m43: self.m43.$method(rhs.m43),
+                    m44: self.m44.$method(rhs.m44),
+                }
+            }
+        }
+        impl<T: Numeric> $op for &Matrix4<T> {
+            type Output = Matrix4<T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Matrix4 {
+                    m11: self.m11.$method(rhs.m11),
+                    m12: self.m12.$method(rhs.m12),
+                    m13: self.m13.$method(rhs.m13),
+                    m14: self.m14.$method(rhs.m14),
+                    m21: self.m21.$method(rhs.m21),
+                    m22: self.m22.$method(rhs.m22),
+                    m23: self.m23.$method(rhs.m23),
+                    m24: self.m24.$method(rhs.m24),
+                    m31: self.m31.$method(rhs.m31),
+                    m32: self.m32.$method(rhs.m32),
+                    m33: self.m33.$method(rhs.m33),
+                    m34: self.m34.$method(rhs.m34),
+                    m41: self.m41.$method(rhs.m41),
+                    m42: self.m42.$method(rhs.m42),
+                    m43: self.m43.$method(rhs.m43),
+                    m44: self.m44.$method(rhs.m44),
+                }
+            }
+        }
+        impl<T: Numeric> $op<Matrix4<T>> for &Matrix4<T> {
+            type Output = Matrix4<T>;
+
+            fn $method(self, rhs: Matrix4<T>) -> Self::Output {
+                Matrix4 {
+                    m11: self.m11.$method(rhs.m11),
+                    m12: self.m12.$method(rhs.m12),
+                    m13: self.m13.$method(rhs.m13),
+                    m14: self.m14.$method(rhs.m14),
+                    m21: self.m21.$method(rhs.m21),
+                    m22: self.m22.$method(rhs.m22),
+                    m23: self.m23.$method(rhs.m23),
+                    m24: self.m24.$method(rhs.m24),
+                    m31: self.m31.$method(rhs.m31),
+                    m32: self.m32.$method(rhs.m32),
+                    m33: self.m33.$method(rhs.m33),
+                    m34: self.m34.$method(rhs.m34),
+                    m41: self.m41.$method(rhs.m41),
+                    m42: self.m42.$method(rhs.m42),
+                    m43: self.m43.$method(rhs.m43),
+                    m44: self.m44.$method(rhs.m44),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign for Matrix4<T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.m11 = self.m11.$method(rhs.m11);
+                self.m12 = self.m12.$method(rhs.m12);
+                self.m13 = self.m13.$method(rhs.m13);
+                self.m14 = self.m14.$method(rhs.m14);
+                self.m21 = self.m21.$method(rhs.m21);
+                self.m22 = self.m22.$method(rhs.m22);
+                self.m23 = self.m23.$method(rhs.m23);
+                self.m24 = self.m24.$method(rhs.m24);
+                self.m31 = self.m31.$method(rhs.m31);
+                self.m32 = self.m32.$method(rhs.m32);
+                self.m33 = self.m33.$method(rhs.m33);
+                self.m34 = self.m34.$method(rhs.m34);
+                self.m41 = self.m41.$method(rhs.m41);
+                self.m42 = self.m42.$method(rhs.m42);
+                self.m43 = self.m43.$method(rhs.m43);
+                self.m44 = self.m44.$method(rhs.m44);
+            }
+        }
+        impl<T: Numeric> $op_assign<&Matrix4<T>> for Matrix4<T> {
+            fn $method_assign(&mut self, rhs: &Self) {
+                self.m11 = self.m11.$method(rhs.m11);
+                self.m12 = self.m12.$method(rhs.m12);
+                self.m13 = self.m13.$method(rhs.m13);
+                self.m14 = self.m14.$method(rhs.m14);
+                self.m21 = self.m21.$method(rhs.m21);
+                self.m22 = self.m22.$method(rhs.m22);
+                self.m23 = self.m23.$method(rhs.m23);
+                self.m24 = self.m24.$method(rhs.m24);
+                self.m31 = self.m31.$method(rhs.m31);
+                self.m32 = self.m32.$method(rhs.m32);
+                self.m33 = self.m33.$method(rhs.m33);
+                self.m34 = self.m34.$method(rhs.m34);
+                self.m41 = self.m41.$method(rhs.m41);
+                self.m42 = self.m42.$method(rhs.m42);
+                self.m43 = self.m43.$method(rhs.m43);
+                self.m44 = self.m44.$method(rhs.m44);
+            }
+        }
+    };
+    () => {};
+}
+impl_matrix4_op!(Add, AddAssign, add, add_assign);
+impl_matrix4_op!(Sub, SubAssign, sub, sub_assign);
+
+macro_rules! impl_scalar_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op<T> for Matrix4<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                Self {
+                    m11: self.m11.$method(rhs),
+                    m12: self.m12.$method(rhs),
+                    m13: self.m13.$method(rhs),
+                    m14: self.m14.$method(rhs),
+                    m21: self.m21.$method(rhs),
+                    m22: self.m22.$method(rhs),
+                    m23: self.m23.$method(rhs),
+                    m24: self.m24.$method(rhs),
+                    m31: self.m31.$method(rhs),
+                    m32: self.m32.$method(rhs),
+                    m33: self.m33.$method(rhs),
+                    m34: self.m34.$method(rhs),
+                    m41: self.m41.$method(rhs),
+                    m42: self.m42.$method(rhs),
+                    m43: self.m43.$method(rhs),
+                    m44: self.m44.$method(rhs),
+                }
+            }
+        }
+        impl<T: Numeric> $op<T> for &Matrix4<T> {
+            type Output = Matrix4<T>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                Matrix4 {
+                    m11: self.m11.$method(rhs),
+                    m12: self.m12.$method(rhs),
+                    m13: self.m13.$method(rhs),
+                    m14: self.m14.$method(rhs),
+                    m21: self.m21.$method(rhs),
+                    m22: self.m22.$method(rhs),
+                    m23: self.m23.$method(rhs),
+                    m24: self.m24.$method(rhs),
+                    m31: self.m31.$method(rhs),
+                    m32: self.m32.$method(rhs),
+                    m33: self.m33.$method(rhs),
+                    m34: self.m34.$method(rhs),
+                    m41: self.m41.$method(rhs),
+                    m42: self.m42.$method(rhs),
+                    m43: self.m43.$method(rhs),
+                    m44: self.m44.$method(rhs),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign<T> for Matrix4<T> {
+            fn $method_assign(&mut self, rhs: T) {
+                self.m11 = self.m11.$method(rhs);
+                self.m12 = self.m12.$method(rhs);
+                self.m13 = self.m13.$method(rhs);
+                self.m14 = self.m14.$method(rhs);
+                self.m21 = self.m21.$method(rhs);
+                self.m22 = self.m22.$method(rhs);
+                self.m23 = self.m23.$method(rhs);
+                self.m24 = self.m24.$method(rhs);
+                self.m31 = self.m31.$method(rhs);
+                self.m32 = self.m32.$method(rhs);
+                self.m33 = self.m33.$method(rhs);
+                self.m34 = self.m34.$method(rhs);
+                self.m41 = self.m41.$method(rhs);
+                self.m42 = self.m42.$method(rhs);
+                self.m43 = self.m43.$method(rhs);
+                self.m44 = self.m44.$method(rhs);
+            }
+        }
+    };
+    () => {};
+}
+
+impl_scalar_op!(Mul, MulAssign, mul, mul_assign);
+impl_scalar_op!(Div, DivAssign, div, div_assign);
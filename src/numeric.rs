@@ -1,5 +1,7 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
+/// Scalar usable in vectors and matrices. There is no blanket
+/// implementation, custom scalar types implement it themselves.
 pub trait Numeric:
     Copy
     + Add<Output = Self>
@@ -10,16 +12,143 @@ pub trait Numeric:
     + PartialEq
     + Default
 {
+    fn zero() -> Self;
+    fn one() -> Self;
 }
 
-impl<T> Numeric for T where
-    T: Copy
-        + Add<Output = Self>
-        + Sub<Output = Self>
-        + Mul<Output = Self>
-        + Div<Output = Self>
-        + PartialOrd
-        + PartialEq
-        + Default
-{
+macro_rules! impl_numeric {
+    ($zero:literal, $one:literal, $($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+impl_numeric!(0, 1, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_numeric!(0.0, 1.0, f32, f64);
+
+pub trait Signed: Numeric + Neg<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Integer arithmetic that returns `None` on overflow or division by zero
+/// instead of panicking or wrapping.
+pub trait Checked: Numeric {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {
+        $(
+            impl Checked for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_checked!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+pub trait Real: Signed {
+    fn epsilon() -> Self;
+    fn pi() -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn to_radians(self) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_real {
+    ($t:ident) => {
+        impl Real for $t {
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+            fn pi() -> Self {
+                std::$t::consts::PI
+            }
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    };
 }
+impl_real!(f32);
+impl_real!(f64);
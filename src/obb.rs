@@ -0,0 +1,179 @@
+use crate::{
+    aabb::Aabb,
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Oriented bounding box. The columns of `orientation` are the unit local
+/// axes, `half_extents` the half sizes along them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Obb<T: Numeric> {
+    pub center: Vector<T>,
+    pub half_extents: Vector<T>,
+    pub orientation: Matrix<T>,
+}
+impl<T: Numeric> Obb<T> {
+    pub fn new(center: Vector<T>, half_extents: Vector<T>, orientation: Matrix<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            orientation,
+        }
+    }
+
+    pub fn axis(&self, index: usize) -> Vector<T> {
+        self.orientation.column(index)
+    }
+
+    pub fn corners(&self) -> [Vector<T>; 8] {
+        let x = self.axis(0) * self.half_extents.x;
+        let y = self.axis(1) * self.half_extents.y;
+        let z = self.axis(2) * self.half_extents.z;
+        let c = &self.center;
+        [
+            c - &x - &y - &z,
+            c + &x - &y - &z,
+            c - &x + &y - &z,
+            c + &x + &y - &z,
+            c - &x - &y + &z,
+            c + &x - &y + &z,
+            c - &x + &y + &z,
+            c + &x + &y + &z,
+        ]
+    }
+
+    /// Tight axis-aligned bounds of the box.
+    pub fn aabb(&self) -> Aabb<T> {
+        let zero = T::zero();
+        Aabb::new(
+            Vector::new(zero, zero, zero) - &self.half_extents,
+            self.half_extents.clone(),
+        )
+        .transform(&self.orientation, &self.center)
+    }
+}
+impl<T: Real> Obb<T> {
+    pub fn from_aabb(aabb: &Aabb<T>) -> Self {
+        Self {
+            center: aabb.center(),
+            half_extents: aabb.extents(),
+            orientation: Matrix::unity(),
+        }
+    }
+
+    /// Fits a box to `points` with axes along the eigenvectors of their
+    /// covariance matrix. Returns `None` for an empty slice.
+    pub fn from_points(points: &[Vector<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let count = T::from_f64(points.len() as f64);
+        let mean = points.iter().fold(Vector::default(), |sum, p| sum + p) / count;
+        let mut covariance = [[T::zero(); 3]; 3];
+        for point in points {
+            let d = point - &mean;
+            let d = [d.x, d.y, d.z];
+            for (row, &di) in covariance.iter_mut().zip(d.iter()) {
+                for (entry, &dj) in row.iter_mut().zip(d.iter()) {
+                    *entry = *entry + di * dj / count;
+                }
+            }
+        }
+        let orientation = Matrix::from_rows(covariance).symmetric_eigen().vectors;
+
+        let local = |p: &Vector<T>| p * &orientation;
+        let first = local(&points[0]);
+        let (min, max) = points[1..]
+            .iter()
+            .map(local)
+            .fold((first.clone(), first), |(min, max), p| {
+                (min.min(&p), max.max(&p))
+            });
+        let two = T::from_f64(2.0);
+        Some(Self {
+            center: &orientation * ((&min + &max) / two),
+            half_extents: (max - min) / two,
+            orientation,
+        })
+    }
+
+    /// Point of the box closest to `point`, which is `point` itself if it
+    /// lies inside.
+    pub fn closest_point(&self, point: &Vector<T>) -> Vector<T> {
+        let d = point - &self.center;
+        let extents = [
+            self.half_extents.x,
+            self.half_extents.y,
+            self.half_extents.z,
+        ];
+        (0..3).fold(self.center.clone(), |closest, i| {
+            let axis = self.axis(i);
+            let distance = d.dot(&axis).clamp(-extents[i], extents[i]);
+            closest + axis * distance
+        })
+    }
+
+    pub fn distance_squared(&self, point: &Vector<T>) -> T {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    pub fn contains(&self, point: &Vector<T>) -> bool {
+        let d = point - &self.center;
+        d.dot(&self.axis(0)).abs() <= self.half_extents.x
+            && d.dot(&self.axis(1)).abs() <= self.half_extents.y
+            && d.dot(&self.axis(2)).abs() <= self.half_extents.z
+    }
+
+    /// Separating axis test over the 15 candidate axes: the face normals of
+    /// both boxes and the cross products of their edges (Ericson, "Real-Time
+    /// Collision Detection", 4.4.1).
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let a = [
+            self.half_extents.x,
+            self.half_extents.y,
+            self.half_extents.z,
+        ];
+        let b = [rhs.half_extents.x, rhs.half_extents.y, rhs.half_extents.z];
+        // Rotation expressing rhs in the frame of self.
+        let r = (self.orientation.transpose() * &rhs.orientation).to_rows();
+        // The epsilon keeps near-parallel edges, whose cross product is close
+        // to zero, from producing a false separating axis.
+        let abs_r = r.map(|row| row.map(|e| e.abs() + T::epsilon()));
+        let t = (&rhs.center - &self.center) * &self.orientation;
+        let t = [t.x, t.y, t.z];
+
+        for i in 0..3 {
+            let ra = a[i];
+            let rb = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let ra = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+            let rb = b[j];
+            let distance = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if distance.abs() > ra + rb {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                let rb = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                let distance = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if distance.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        self.intersects(&Obb::from_aabb(aabb))
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    matrix::Matrix,
+    matrix4::Matrix4,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// The points `p` with `normal.dot(p) == offset`. Points with a positive
+/// signed distance lie on the side the normal points to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plane<T: Numeric> {
+    pub normal: Vector<T>,
+    pub offset: T,
+}
+impl<T: Numeric> Plane<T> {
+    pub fn new(normal: Vector<T>, offset: T) -> Self {
+        Self { normal, offset }
+    }
+
+    pub fn from_point_normal(point: &Vector<T>, normal: Vector<T>) -> Self {
+        Self {
+            offset: normal.dot(point),
+            normal,
+        }
+    }
+}
+impl<T: Real> Plane<T> {
+    /// Plane through three points with a unit normal facing the side from
+    /// which `a`, `b`, `c` appear counter-clockwise. Returns `None` for
+    /// collinear points.
+    pub fn from_points(a: &Vector<T>, b: &Vector<T>, c: &Vector<T>) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a));
+        if normal.magnitude_squared() == T::zero() {
+            return None;
+        }
+        Some(Self::from_point_normal(a, normal.normalize()))
+    }
+
+    /// Scales the plane to a unit normal without moving it.
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.normal.magnitude();
+        Self {
+            normal: &self.normal / magnitude,
+            offset: self.offset / magnitude,
+        }
+    }
+
+    pub fn signed_distance(&self, point: &Vector<T>) -> T {
+        (self.normal.dot(point) - self.offset) / self.normal.magnitude()
+    }
+
+    /// Orthogonal projection of `point` onto the plane.
+    pub fn project_point(&self, point: &Vector<T>) -> Vector<T> {
+        let t = (self.normal.dot(point) - self.offset) / self.normal.magnitude_squared();
+        point - &self.normal * t
+    }
+
+    pub fn reflect_point(&self, point: &Vector<T>) -> Vector<T> {
+        let t = (self.normal.dot(point) - self.offset) / self.normal.magnitude_squared();
+        point - &self.normal * (t * T::from_f64(2.0))
+    }
+
+    /// Householder reflection `I - 2 n n^T` about the plane through the origin
+    /// parallel to this one. Use it for directions and normals.
+    pub fn reflection_matrix(&self) -> Matrix<T> {
+        let n = self.normal.normalize();
+        let two = T::from_f64(2.0);
+        let one = T::one();
+        Matrix::new(
+            one - two * n.x * n.x,
+            -two * n.x * n.y,
+            -two * n.x * n.z,
+            -two * n.y * n.x,
+            one - two * n.y * n.y,
+            -two * n.y * n.z,
+            -two * n.z * n.x,
+            -two * n.z * n.y,
+            one - two * n.z * n.z,
+        )
+    }
+
+    /// Affine reflection of points about the plane, as used for mirror
+    /// cameras.
+    pub fn reflection(&self) -> Matrix4<T> {
+        let plane = self.normalize();
+        let translation = &plane.normal * (T::from_f64(2.0) * plane.offset);
+        let mut m = Matrix4::from(plane.reflection_matrix());
+        m.m14 = translation.x;
+        m.m24 = translation.y;
+        m.m34 = translation.z;
+        m
+    }
+
+    /// The common point of three planes, found by solving the linear system
+    /// of their equations. Returns `None` if the normals are linearly
+    /// dependent.
+    pub fn intersect_planes(a: &Self, b: &Self, c: &Self) -> Option<Vector<T>> {
+        Matrix::from_rows([
+            [a.normal.x, a.normal.y, a.normal.z],
+            [b.normal.x, b.normal.y, b.normal.z],
+            [c.normal.x, c.normal.y, c.normal.z],
+        ])
+        .solve(&Vector::new(a.offset, b.offset, c.offset))
+    }
+
+    /// Intersection with the infinite line through `point` along
+    /// `direction`. Returns `None` if the line is parallel to the plane.
+    pub fn intersect_line(&self, point: &Vector<T>, direction: &Vector<T>) -> Option<Vector<T>> {
+        let denominator = self.normal.dot(direction);
+        if denominator == T::zero() {
+            return None;
+        }
+        let t = (self.offset - self.normal.dot(point)) / denominator;
+        Some(point + direction * t)
+    }
+}
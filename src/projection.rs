@@ -0,0 +1,201 @@
+use crate::{matrix4::Matrix4, numeric::Real};
+
+/// Depth range of normalized device coordinates.
+/// OpenGL uses `NegativeOneToOne`, Vulkan, WebGPU and D3D use `ZeroToOne`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    #[default]
+    NegativeOneToOne,
+    ZeroToOne,
+}
+impl DepthRange {
+    fn bounds<T: Real>(&self, reversed: bool) -> (T, T) {
+        let (near, far) = match self {
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+        };
+        if reversed {
+            (far, near)
+        } else {
+            (near, far)
+        }
+    }
+}
+
+/// Handedness of view space. Right-handed cameras look down -z,
+/// left-handed cameras look down +z.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    #[default]
+    Right,
+    Left,
+}
+impl Handedness {
+    pub(crate) fn forward_sign<T: Real>(&self) -> T {
+        match self {
+            Handedness::Right => -T::one(),
+            Handedness::Left => T::one(),
+        }
+    }
+}
+
+impl<T: Real> Matrix4<T> {
+    /// Perspective projection with vertical field of view `fovy` in radians.
+    /// In debug builds this and the other perspective constructors panic
+    /// unless `0 < fovy < pi`, `aspect > 0`, `near > 0` and `far != near`,
+    /// which would otherwise produce infinite or NaN entries.
+    pub fn perspective(
+        fovy: T,
+        aspect: T,
+        near: T,
+        far: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(false);
+        Self::perspective_with_bounds(fovy, aspect, near, Some(far), ndc_near, ndc_far, handedness)
+    }
+
+    pub fn perspective_infinite(
+        fovy: T,
+        aspect: T,
+        near: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(false);
+        Self::perspective_with_bounds(fovy, aspect, near, None, ndc_near, ndc_far, handedness)
+    }
+
+    /// Perspective projection mapping the near plane to the far end of the
+    /// depth range and the far plane to the near end.
+    pub fn perspective_reversed_z(
+        fovy: T,
+        aspect: T,
+        near: T,
+        far: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(true);
+        Self::perspective_with_bounds(fovy, aspect, near, Some(far), ndc_near, ndc_far, handedness)
+    }
+
+    pub fn perspective_infinite_reversed_z(
+        fovy: T,
+        aspect: T,
+        near: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(true);
+        Self::perspective_with_bounds(fovy, aspect, near, None, ndc_near, ndc_far, handedness)
+    }
+
+    /// Orthographic projection of the given box. In debug builds this and
+    /// [`Matrix4::orthographic_reversed_z`] panic if the box is empty along
+    /// any axis.
+    #[allow(clippy::too_many_arguments)]
+    pub fn orthographic(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(false);
+        Self::orthographic_with_bounds(
+            left, right, bottom, top, near, far, ndc_near, ndc_far, handedness,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn orthographic_reversed_z(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: DepthRange,
+        handedness: Handedness,
+    ) -> Self {
+        let (ndc_near, ndc_far) = depth.bounds(true);
+        Self::orthographic_with_bounds(
+            left, right, bottom, top, near, far, ndc_near, ndc_far, handedness,
+        )
+    }
+
+    // With d the distance along the view direction, depth is mapped to
+    // ndc_z = a + b / d, which for an infinite far plane degenerates to
+    // a = ndc_far and b = (ndc_near - ndc_far) * near.
+    fn perspective_with_bounds(
+        fovy: T,
+        aspect: T,
+        near: T,
+        far: Option<T>,
+        ndc_near: T,
+        ndc_far: T,
+        handedness: Handedness,
+    ) -> Self {
+        debug_assert!(
+            fovy > T::zero() && fovy < T::pi(),
+            "field of view out of (0, pi)"
+        );
+        debug_assert!(aspect > T::zero(), "aspect ratio must be positive");
+        debug_assert!(near > T::zero(), "near plane must be in front of the eye");
+        debug_assert!(far != Some(near), "near and far plane coincide");
+        let s = handedness.forward_sign();
+        let f = T::one() / (fovy / T::from_f64(2.0)).tan();
+        let (a, b) = match far {
+            Some(far) => (
+                (ndc_far * far - ndc_near * near) / (far - near),
+                (ndc_near - ndc_far) * near * far / (far - near),
+            ),
+            None => (ndc_far, (ndc_near - ndc_far) * near),
+        };
+        Self {
+            m11: f / aspect,
+            m22: f,
+            m33: a * s,
+            m34: b,
+            m43: s,
+            ..Default::default()
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn orthographic_with_bounds(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        ndc_near: T,
+        ndc_far: T,
+        handedness: Handedness,
+    ) -> Self {
+        debug_assert!(
+            left != right && bottom != top && near != far,
+            "empty orthographic volume"
+        );
+        let s = handedness.forward_sign();
+        let two = T::from_f64(2.0);
+        let a = (ndc_far - ndc_near) / (far - near);
+        let b = ndc_near - a * near;
+        Self {
+            m11: two / (right - left),
+            m14: -(right + left) / (right - left),
+            m22: two / (top - bottom),
+            m24: -(top + bottom) / (top - bottom),
+            m33: a * s,
+            m34: b,
+            m44: T::one(),
+            ..Default::default()
+        }
+    }
+}
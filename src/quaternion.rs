@@ -0,0 +1,472 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real, Signed},
+    vector::Vector,
+};
+
+#[derive(PartialEq, Eq)]
+pub struct Quaternion<T: Numeric> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+impl<T: Numeric> Default for Quaternion<T> {
+    fn default() -> Self {
+        Self {
+            w: T::default(),
+            x: T::default(),
+            y: T::default(),
+            z: T::default(),
+        }
+    }
+}
+impl<T: Numeric> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn from_scalar_vector(w: T, v: &Vector<T>) -> Self {
+        Self {
+            w,
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+
+    pub fn vector(&self) -> Vector<T> {
+        Vector::new(self.x, self.y, self.z)
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            w: T::one(),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        let zero = T::zero();
+        Self {
+            w: self.w,
+            x: zero - self.x,
+            y: zero - self.y,
+            z: zero - self.z,
+        }
+    }
+
+    pub fn lerp(&self, rhs: &Self, t: T) -> Self {
+        Self {
+            w: self.w + (rhs.w - self.w) * t,
+            x: self.x + (rhs.x - self.x) * t,
+            y: self.y + (rhs.y - self.y) * t,
+            z: self.z + (rhs.z - self.z) * t,
+        }
+    }
+
+    fn hamilton(lhs: &Self, rhs: &Self) -> Self {
+        Self {
+            w: lhs.w * rhs.w - lhs.x * rhs.x - lhs.y * rhs.y - lhs.z * rhs.z,
+            x: lhs.w * rhs.x + lhs.x * rhs.w + lhs.y * rhs.z - lhs.z * rhs.y,
+            y: lhs.w * rhs.y - lhs.x * rhs.z + lhs.y * rhs.w + lhs.z * rhs.x,
+            z: lhs.w * rhs.z + lhs.x * rhs.y - lhs.y * rhs.x + lhs.z * rhs.w,
+        }
+    }
+}
+impl<T: Real> Quaternion<T> {
+    pub fn from_axis_angle(radians: T, axis: &Vector<T>) -> Self {
+        let axis = axis.normalize();
+        let half = radians / T::from_f64(2.0);
+        let s = half.sin();
+        Self {
+            w: half.cos(),
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+        }
+    }
+
+    pub fn from_axis_angle_degree(degree: T, axis: &Vector<T>) -> Self {
+        Self::from_axis_angle(degree.to_radians(), axis)
+    }
+
+    /// Returns the rotation angle in radians and the unit rotation axis.
+    /// The identity rotation reports the x axis.
+    pub fn to_axis_angle(&self) -> (T, Vector<T>) {
+        let q = self.normalize();
+        let w = q.w.clamp(-T::one(), T::one());
+        let s = (T::one() - w * w).sqrt();
+        if s < T::epsilon() {
+            return (T::zero(), Vector::x_axis());
+        }
+        (
+            T::from_f64(2.0) * w.acos(),
+            Vector::new(q.x / s, q.y / s, q.z / s),
+        )
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        self / self.magnitude()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let mag_squared = self.magnitude_squared();
+        if mag_squared == T::zero() {
+            return None;
+        }
+        Some(self.conjugate() / mag_squared)
+    }
+
+    /// Normalized linear interpolation along the shortest path.
+    pub fn nlerp(&self, rhs: &Self, t: T) -> Self {
+        let rhs = self.shortest_path_to(rhs);
+        self.lerp(&rhs, t).normalize()
+    }
+
+    /// Spherical linear interpolation along the shortest path. Falls back to
+    /// `nlerp` when both orientations are nearly identical.
+    pub fn slerp(&self, rhs: &Self, t: T) -> Self {
+        let rhs = self.shortest_path_to(rhs);
+        let cos_theta = self.dot(&rhs);
+        if cos_theta > T::one() - T::from_f64(1e-4) {
+            return self.lerp(&rhs, t).normalize();
+        }
+        let theta = cos_theta.min(T::one()).acos();
+        let sin_theta = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        (self * a + rhs * b).normalize()
+    }
+
+    fn shortest_path_to(&self, rhs: &Self) -> Self {
+        if self.dot(rhs) < T::zero() {
+            -rhs
+        } else {
+            rhs.clone()
+        }
+    }
+
+    /// Rotates `v` by this quaternion, which is assumed to be of unit length.
+    pub fn rotate(&self, v: &Vector<T>) -> Vector<T> {
+        let two = T::from_f64(2.0);
+        let u = self.vector();
+        let t = u.cross(v) * two;
+        v + t.clone() * self.w + u.cross(&t)
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Interpolates between two rotation matrices through their quaternions.
+    pub fn slerp(&self, rhs: &Self, t: T) -> Self {
+        let a = Quaternion::from(self);
+        let b = Quaternion::from(rhs);
+        Matrix::from(a.slerp(&b, t))
+    }
+
+    pub fn nlerp(&self, rhs: &Self, t: T) -> Self {
+        let a = Quaternion::from(self);
+        let b = Quaternion::from(rhs);
+        Matrix::from(a.nlerp(&b, t))
+    }
+}
+
+impl<T: Real> From<&Quaternion<T>> for Matrix<T> {
+    fn from(q: &Quaternion<T>) -> Self {
+        let q = q.normalize();
+        let one = T::one();
+        let two = T::from_f64(2.0);
+        Matrix::new(
+            one - two * (q.y * q.y + q.z * q.z),
+            two * (q.x * q.y - q.w * q.z),
+            two * (q.x * q.z + q.w * q.y),
+            two * (q.x * q.y + q.w * q.z),
+            one - two * (q.x * q.x + q.z * q.z),
+            two * (q.y * q.z - q.w * q.x),
+            two * (q.x * q.z - q.w * q.y),
+            two * (q.y * q.z + q.w * q.x),
+            one - two * (q.x * q.x + q.y * q.y),
+        )
+    }
+}
+impl<T: Real> From<Quaternion<T>> for Matrix<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        Self::from(&q)
+    }
+}
+impl<T: Real> From<&Matrix<T>> for Quaternion<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        let two = T::from_f64(2.0);
+        let four = T::from_f64(4.0);
+        let trace = m.m11 + m.m22 + m.m33;
+        let q = if trace > T::zero() {
+            let s = (trace + T::one()).sqrt() * two;
+            Self {
+                w: s / four,
+                x: (m.m32 - m.m23) / s,
+                y: (m.m13 - m.m31) / s,
+                z: (m.m21 - m.m12) / s,
+            }
+        } else if m.m11 > m.m22 && m.m11 > m.m33 {
+            let s = (T::one() + m.m11 - m.m22 - m.m33).sqrt() * two;
+            Self {
+                w: (m.m32 - m.m23) / s,
+                x: s / four,
+                y: (m.m12 + m.m21) / s,
+                z: (m.m13 + m.m31) / s,
+            }
+        } else if m.m22 > m.m33 {
+            let s = (T::one() + m.m22 - m.m11 - m.m33).sqrt() * two;
+            Self {
+                w: (m.m13 - m.m31) / s,
+                x: (m.m12 + m.m21) / s,
+                y: s / four,
+                z: (m.m23 + m.m32) / s,
+            }
+        } else {
+            let s = (T::one() + m.m33 - m.m11 - m.m22).sqrt() * two;
+            Self {
+                w: (m.m21 - m.m12) / s,
+                x: (m.m13 + m.m31) / s,
+                y: (m.m23 + m.m32) / s,
+                z: s / four,
+            }
+        };
+        q.normalize()
+    }
+}
+impl<T: Real> From<Matrix<T>> for Quaternion<T> {
+    fn from(m: Matrix<T>) -> Self {
+        Self::from(&m)
+    }
+}
+
+impl<T: Numeric + Debug> Debug for Quaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Quaternion {{ w: {:?}, x: {:?}, y: {:?}, z: {:?} }}",
+            self.w, self.x, self.y, self.z
+        )
+    }
+}
+
+impl<T: Numeric + Clone> Clone for Quaternion<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.w, self.x, self.y, self.z)
+    }
+}
+
+impl<T: Numeric> Mul for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::hamilton(&self, &rhs)
+    }
+}
+impl<T: Numeric> Mul<&Quaternion<T>> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: &Self) -> Self::Output {
+        Quaternion::hamilton(&self, rhs)
+    }
+}
+impl<T: Numeric> Mul<Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Quaternion<T>) -> Self::Output {
+        Quaternion::hamilton(self, &rhs)
+    }
+}
+impl<T: Numeric> Mul for &Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::hamilton(self, rhs)
+    }
+}
+impl<T: Numeric> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = Quaternion::hamilton(self, &rhs);
+    }
+}
+impl<T: Numeric> MulAssign<&Quaternion<T>> for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = Quaternion::hamilton(self, rhs);
+    }
+}
+
+impl<T: Real> Mul<Vector<T>> for Quaternion<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        self.rotate(&rhs)
+    }
+}
+impl<T: Real> Mul<&Vector<T>> for Quaternion<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+impl<T: Real> Mul<Vector<T>> for &Quaternion<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        self.rotate(&rhs)
+    }
+}
+impl<T: Real> Mul<&Vector<T>> for &Quaternion<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+impl<T: Signed> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+impl<T: Signed> Neg for &Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+macro_rules! impl_quaternion_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op for Quaternion<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self {
+                    w: self.w.$method(rhs.w),
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                }
+            }
+        }
+        impl<T: Numeric> $op<&Quaternion<T>> for Quaternion<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                Self {
+                    w: self.w.$method(rhs.w),
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                }
+            }
+        }
+        impl<T: Numeric> $op<Quaternion<T>> for &Quaternion<T> {
+            type Output = Quaternion<T>;
+
+            fn $method(self, rhs: Quaternion<T>) -> Self::Output {
+                Quaternion {
+                    w: self.w.$method(rhs.w),
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                }
+            }
+        }
+        impl<T: Numeric> $op for &Quaternion<T> {
+            type Output = Quaternion<T>;
+
+            fn $method(self, rhs: &Quaternion<T>) -> Self::Output {
+                Quaternion {
+                    w: self.w.$method(rhs.w),
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign for Quaternion<T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.w = self.w.$method(rhs.w);
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+                self.z = self.z.$method(rhs.z);
+            }
+        }
+        impl<T: Numeric> $op_assign<&Quaternion<T>> for Quaternion<T> {
+            fn $method_assign(&mut self, rhs: &Self) {
+                self.w = self.w.$method(rhs.w);
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+                self.z = self.z.$method(rhs.z);
+            }
+        }
+    };
+    () => {};
+}
+impl_quaternion_op!(Add, AddAssign, add, add_assign);
+impl_quaternion_op!(Sub, SubAssign, sub, sub_assign);
+
+macro_rules! impl_scalar_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op<T> for Quaternion<T> {
+            type Output = Self;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Self {
+                    w: self.w.$method(scalar),
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                    z: self.z.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op<T> for &Quaternion<T> {
+            type Output = Quaternion<T>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Quaternion {
+                    w: self.w.$method(scalar),
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                    z: self.z.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign<T> for Quaternion<T> {
+            fn $method_assign(&mut self, scalar: T) {
+                self.w = self.w.$method(scalar);
+                self.x = self.x.$method(scalar);
+                self.y = self.y.$method(scalar);
+                self.z = self.z.$method(scalar);
+            }
+        }
+    };
+    () => {};
+}
+impl_scalar_op!(Mul, MulAssign, mul, mul_assign);
+impl_scalar_op!(Div, DivAssign, div, div_assign);
@@ -0,0 +1,202 @@
+use crate::{
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Half-line `origin + t * direction` for `t >= 0`. The direction does not
+/// have to be normalized; hit distances are measured in multiples of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ray<T: Numeric> {
+    pub origin: Vector<T>,
+    pub direction: Vector<T>,
+}
+
+/// Result of a ray query. `normal` is the unit surface normal at `point`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RayHit<T: Numeric> {
+    pub distance: T,
+    pub point: Vector<T>,
+    pub normal: Vector<T>,
+}
+
+/// Ray hit on a triangle together with the barycentric coordinates of the
+/// hit point with respect to the triangle corners `a`, `b` and `c`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangleHit<T: Numeric> {
+    pub hit: RayHit<T>,
+    pub barycentric: Vector<T>,
+}
+
+impl<T: Numeric> Ray<T> {
+    pub fn new(origin: Vector<T>, direction: Vector<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    pub fn at(&self, t: T) -> Vector<T> {
+        &self.origin + &self.direction * t
+    }
+}
+impl<T: Real> Ray<T> {
+    fn hit(&self, distance: T, normal: Vector<T>) -> RayHit<T> {
+        RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        }
+    }
+
+    /// Intersects the plane of points `p` with `normal.dot(p) == offset`. The
+    /// reported normal is `normal` normalized, whichever side is hit.
+    pub fn intersect_plane(&self, normal: &Vector<T>, offset: T) -> Option<RayHit<T>> {
+        let denominator = normal.dot(&self.direction);
+        if denominator == T::zero() {
+            return None;
+        }
+        let distance = (offset - normal.dot(&self.origin)) / denominator;
+        if distance < T::zero() {
+            return None;
+        }
+        Some(self.hit(distance, normal.normalize()))
+    }
+
+    /// Intersects a sphere. A ray starting inside the sphere hits it on the
+    /// way out; the normal always points outwards. Spheres without a positive
+    /// radius are never hit.
+    pub fn intersect_sphere(&self, center: &Vector<T>, radius: T) -> Option<RayHit<T>> {
+        let a = self.direction.magnitude_squared();
+        if a == T::zero() || radius <= T::zero() {
+            return None;
+        }
+        let m = &self.origin - center;
+        let b = m.dot(&self.direction);
+        let c = m.magnitude_squared() - radius * radius;
+        if c > T::zero() && b > T::zero() {
+            return None;
+        }
+        let discriminant = b * b - a * c;
+        if discriminant < T::zero() {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let distance = if near >= T::zero() {
+            near
+        } else {
+            (-b + root) / a
+        };
+        let point = self.at(distance);
+        let normal = (&point - center) / radius;
+        Some(RayHit {
+            distance,
+            point,
+            normal,
+        })
+    }
+
+    /// Intersects the axis-aligned box spanned by `min` and `max` with the
+    /// slab method. A ray starting inside the box hits it on the way out; the
+    /// normal is the outward normal of the face that was hit.
+    pub fn intersect_aabb(&self, min: &Vector<T>, max: &Vector<T>) -> Option<RayHit<T>> {
+        let origin = [self.origin.x, self.origin.y, self.origin.z];
+        let direction = [self.direction.x, self.direction.y, self.direction.z];
+        let min = [min.x, min.y, min.z];
+        let max = [max.x, max.y, max.z];
+        let one = T::one();
+
+        // Slab parameters with the axis and outward normal sign of the face.
+        let mut entry: Option<(T, usize, T)> = None;
+        let mut exit: Option<(T, usize, T)> = None;
+        for axis in 0..3 {
+            if direction[axis] == T::zero() {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let inverse = one / direction[axis];
+            let t1 = (min[axis] - origin[axis]) * inverse;
+            let t2 = (max[axis] - origin[axis]) * inverse;
+            let (t1, t2, sign) = if t1 > t2 {
+                (t2, t1, one)
+            } else {
+                (t1, t2, -one)
+            };
+            if entry.is_none_or(|(t, _, _)| t1 > t) {
+                entry = Some((t1, axis, sign));
+            }
+            if exit.is_none_or(|(t, _, _)| t2 < t) {
+                exit = Some((t2, axis, -sign));
+            }
+        }
+        let (entry, exit) = (entry?, exit?);
+        if entry.0 > exit.0 || exit.0 < T::zero() {
+            return None;
+        }
+        // A ray starting inside the box leaves through the exit face.
+        let (distance, axis, sign) = if entry.0 >= T::zero() { entry } else { exit };
+        let normal = match axis {
+            0 => Vector::x_axis(),
+            1 => Vector::y_axis(),
+            _ => Vector::z_axis(),
+        } * sign;
+        Some(self.hit(distance, normal))
+    }
+
+    /// Möller–Trumbore intersection with the triangle `a`, `b`, `c`. The
+    /// normal is the geometric normal of the counter-clockwise winding. With
+    /// `cull_backfaces` set, triangles whose normal faces away from the ray
+    /// origin are ignored.
+    pub fn intersect_triangle(
+        &self,
+        a: &Vector<T>,
+        b: &Vector<T>,
+        c: &Vector<T>,
+        cull_backfaces: bool,
+    ) -> Option<TriangleHit<T>> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(&edge2);
+        let determinant = edge1.dot(&p);
+        let threshold = T::epsilon() * edge1.magnitude() * p.magnitude();
+        if cull_backfaces && determinant <= threshold {
+            return None;
+        }
+        if determinant.abs() <= threshold {
+            return None;
+        }
+        let inverse = T::one() / determinant;
+        let s = &self.origin - a;
+        let u = s.dot(&p) * inverse;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+        let q = s.cross(&edge1);
+        let v = self.direction.dot(&q) * inverse;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+        let distance = edge2.dot(&q) * inverse;
+        if distance < T::zero() {
+            return None;
+        }
+        Some(TriangleHit {
+            hit: self.hit(distance, edge1.cross(&edge2).normalize()),
+            barycentric: Vector::new(T::one() - u - v, u, v),
+        })
+    }
+
+    /// Intersects the disc around `center` with the given plane `normal` and
+    /// `radius`. The reported normal is `normal` normalized.
+    pub fn intersect_disc(
+        &self,
+        center: &Vector<T>,
+        normal: &Vector<T>,
+        radius: T,
+    ) -> Option<RayHit<T>> {
+        let hit = self.intersect_plane(normal, normal.dot(center))?;
+        if (&hit.point - center).magnitude_squared() > radius * radius {
+            return None;
+        }
+        Some(hit)
+    }
+}
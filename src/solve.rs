@@ -0,0 +1,291 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Reason why [`Matrix::try_inverse`] refused to invert a matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InverseError<T> {
+    /// The matrix contains NaN or infinite entries.
+    NotFinite,
+    /// The matrix is singular, its rank is below three.
+    Singular,
+    /// The matrix is invertible in theory, but its condition number is too
+    /// large for the inverse to be meaningful.
+    IllConditioned { condition: T },
+}
+impl<T: Debug> Display for InverseError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InverseError::NotFinite => write!(f, "matrix has non-finite entries"),
+            InverseError::Singular => write!(f, "matrix is singular"),
+            InverseError::IllConditioned { condition } => {
+                write!(
+                    f,
+                    "matrix is ill-conditioned (condition number {condition:?})"
+                )
+            }
+        }
+    }
+}
+impl<T: Debug> std::error::Error for InverseError<T> {}
+
+/// LU factorization with partial pivoting, `p * a = l * u`.
+#[derive(Debug, Clone)]
+pub struct Lu<T: Numeric> {
+    // Unit lower triangle holds l below the diagonal, u on and above it.
+    factors: [[T; 3]; 3],
+    permutation: [usize; 3],
+    swaps: usize,
+}
+impl<T: Real> Lu<T> {
+    pub fn l(&self) -> Matrix<T> {
+        let f = &self.factors;
+        let (zero, one) = (T::zero(), T::one());
+        Matrix::from_rows([
+            [one, zero, zero],
+            [f[1][0], one, zero],
+            [f[2][0], f[2][1], one],
+        ])
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let f = &self.factors;
+        let zero = T::zero();
+        Matrix::from_rows([
+            [f[0][0], f[0][1], f[0][2]],
+            [zero, f[1][1], f[1][2]],
+            [zero, zero, f[2][2]],
+        ])
+    }
+
+    /// The row permutation as a matrix.
+    pub fn p(&self) -> Matrix<T> {
+        let mut rows = [[T::zero(); 3]; 3];
+        for (row, &source) in rows.iter_mut().zip(self.permutation.iter()) {
+            row[source] = T::one();
+        }
+        Matrix::from_rows(rows)
+    }
+
+    pub fn determinant(&self) -> T {
+        let f = &self.factors;
+        let det = f[0][0] * f[1][1] * f[2][2];
+        if self.swaps % 2 == 1 {
+            -det
+        } else {
+            det
+        }
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..3).any(|i| self.factors[i][i] == T::zero())
+    }
+
+    /// Solves `a * x = b`, returning `None` for a singular matrix.
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        if self.is_singular() {
+            return None;
+        }
+        let f = &self.factors;
+        let b = [b.x, b.y, b.z];
+        let mut y = [T::zero(); 3];
+        for i in 0..3 {
+            y[i] = b[self.permutation[i]];
+            for j in 0..i {
+                y[i] = y[i] - f[i][j] * y[j];
+            }
+        }
+        let mut x = [T::zero(); 3];
+        for i in (0..3).rev() {
+            x[i] = y[i];
+            for j in i + 1..3 {
+                x[i] = x[i] - f[i][j] * x[j];
+            }
+            x[i] = x[i] / f[i][i];
+        }
+        Some(Vector::new(x[0], x[1], x[2]))
+    }
+}
+
+/// QR factorization by Householder reflections, `a = q * r` with orthogonal
+/// `q` and upper triangular `r`.
+#[derive(Debug, Clone)]
+pub struct Qr<T: Numeric> {
+    pub q: Matrix<T>,
+    pub r: Matrix<T>,
+}
+impl<T: Real> Qr<T> {
+    /// Solves `a * x = b`, returning `None` if `r` has a zero on its diagonal.
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        let r = self.r.to_rows();
+        if (0..3).any(|i| r[i][i] == T::zero()) {
+            return None;
+        }
+        let y = self.q.transpose() * b;
+        let y = [y.x, y.y, y.z];
+        let mut x = [T::zero(); 3];
+        for i in (0..3).rev() {
+            x[i] = y[i];
+            for j in i + 1..3 {
+                x[i] = x[i] - r[i][j] * x[j];
+            }
+            x[i] = x[i] / r[i][i];
+        }
+        Some(Vector::new(x[0], x[1], x[2]))
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    #[allow(clippy::needless_range_loop)]
+    pub fn lu(&self) -> Lu<T> {
+        let mut factors = self.to_rows();
+        let mut permutation = [0, 1, 2];
+        let mut swaps = 0;
+        for k in 0..3 {
+            let pivot = (k..3)
+                .max_by(|&a, &b| {
+                    factors[a][k]
+                        .abs()
+                        .partial_cmp(&factors[b][k].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+            if pivot != k {
+                factors.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            if factors[k][k] == T::zero() {
+                continue;
+            }
+            for i in k + 1..3 {
+                let factor = factors[i][k] / factors[k][k];
+                factors[i][k] = factor;
+                for j in k + 1..3 {
+                    factors[i][j] = factors[i][j] - factor * factors[k][j];
+                }
+            }
+        }
+        Lu {
+            factors,
+            permutation,
+            swaps,
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn qr(&self) -> Qr<T> {
+        let mut r = self.to_rows();
+        let mut q = Matrix::unity().to_rows();
+        for k in 0..2 {
+            let norm = (k..3)
+                .fold(T::zero(), |sum, i| sum + r[i][k] * r[i][k])
+                .sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+            let alpha = if r[k][k] > T::zero() { -norm } else { norm };
+            let mut v = [T::zero(); 3];
+            for i in k..3 {
+                v[i] = r[i][k];
+            }
+            v[k] = v[k] - alpha;
+            let v_squared = v.iter().fold(T::zero(), |sum, &e| sum + e * e);
+            if v_squared == T::zero() {
+                continue;
+            }
+            let two = T::from_f64(2.0);
+            // r <- h * r and q <- q * h with h = I - 2 v v^T / (v^T v).
+            for j in 0..3 {
+                let dot = (k..3).fold(T::zero(), |sum, i| sum + v[i] * r[i][j]);
+                let factor = two * dot / v_squared;
+                for i in k..3 {
+                    r[i][j] = r[i][j] - factor * v[i];
+                }
+            }
+            for row in q.iter_mut() {
+                let dot = (k..3).fold(T::zero(), |sum, j| sum + row[j] * v[j]);
+                let factor = two * dot / v_squared;
+                for j in k..3 {
+                    row[j] = row[j] - factor * v[j];
+                }
+            }
+        }
+        for (i, row) in r.iter_mut().enumerate() {
+            for entry in row.iter_mut().take(i) {
+                *entry = T::zero();
+            }
+        }
+        Qr {
+            q: Matrix::from_rows(q),
+            r: Matrix::from_rows(r),
+        }
+    }
+
+    /// Solves `self * x = b` by LU decomposition, returning `None` if the
+    /// matrix is singular.
+    pub fn solve(&self, b: &Vector<T>) -> Option<Vector<T>> {
+        self.lu().solve(b)
+    }
+
+    /// The 2-norm condition number, the ratio of the largest to the smallest
+    /// singular value. Singular matrices yield infinity.
+    pub fn condition_number(&self) -> T {
+        let sigma = self.svd().singular_values;
+        if sigma.z == T::zero() {
+            return T::from_f64(f64::INFINITY);
+        }
+        sigma.x / sigma.z.abs()
+    }
+
+    /// Moore-Penrose pseudo-inverse. Singular values below `tolerance`
+    /// relative to the largest one are treated as zero.
+    pub fn pseudo_inverse(&self, tolerance: T) -> Matrix<T> {
+        let svd = self.svd();
+        let cutoff = tolerance * svd.singular_values.x;
+        let invert = |sigma: T| {
+            if sigma.abs() > cutoff {
+                T::one() / sigma
+            } else {
+                T::zero()
+            }
+        };
+        let zero = T::zero();
+        let sigma_inverse = Matrix::new(
+            invert(svd.singular_values.x),
+            zero,
+            zero,
+            zero,
+            invert(svd.singular_values.y),
+            zero,
+            zero,
+            zero,
+            invert(svd.singular_values.z),
+        );
+        svd.v * sigma_inverse * svd.u.transpose()
+    }
+
+    /// Inverse that fails instead of producing garbage for matrices whose
+    /// reciprocal condition number is not above `epsilon`.
+    pub fn try_inverse(&self, epsilon: T) -> Result<Matrix<T>, InverseError<T>> {
+        if !self.to_rows().iter().flatten().all(|e| e.is_finite()) {
+            return Err(InverseError::NotFinite);
+        }
+        let lu = self.lu();
+        if lu.is_singular() {
+            return Err(InverseError::Singular);
+        }
+        let condition = self.condition_number();
+        if condition * epsilon >= T::one() {
+            return Err(InverseError::IllConditioned { condition });
+        }
+        let columns = [Vector::x_axis(), Vector::y_axis(), Vector::z_axis()]
+            .map(|e| lu.solve(&e).ok_or(InverseError::Singular));
+        let [c1, c2, c3] = columns;
+        Ok(Matrix::from_columns(&c1?, &c2?, &c3?))
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    aabb::Aabb,
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    plane::Plane,
+    triangle::Triangle,
+    vector::Vector,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sphere<T: Numeric> {
+    pub center: Vector<T>,
+    pub radius: T,
+}
+impl<T: Numeric> Sphere<T> {
+    pub fn new(center: Vector<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn contains(&self, point: &Vector<T>) -> bool {
+        (point - &self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let radii = self.radius + rhs.radius;
+        (&rhs.center - &self.center).magnitude_squared() <= radii * radii
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        aabb.distance_squared(&self.center) <= self.radius * self.radius
+    }
+}
+impl<T: Real> Sphere<T> {
+    pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+        plane.signed_distance(&self.center).abs() <= self.radius
+    }
+
+    /// Approximate bounding sphere (Ritter, "An Efficient Bounding Sphere",
+    /// Graphics Gems). Usually within a few percent of the minimum, computed
+    /// in two passes. Returns `None` for an empty slice.
+    pub fn ritter(points: &[Vector<T>]) -> Option<Self> {
+        let first = points.first()?;
+        let farthest = |from: &Vector<T>| {
+            points
+                .iter()
+                .map(|p| (p, (p - from).magnitude_squared()))
+                .fold((first, T::zero()), |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                })
+                .0
+        };
+        let y = farthest(first);
+        let z = farthest(y);
+        let mut sphere = Self::from_two(y, z);
+        for point in points {
+            let offset = point - &sphere.center;
+            let distance = offset.magnitude();
+            if distance > sphere.radius {
+                let radius = (sphere.radius + distance) / T::from_f64(2.0);
+                sphere.center += offset * ((radius - sphere.radius) / distance);
+                sphere.radius = radius;
+            }
+        }
+        Some(sphere)
+    }
+
+    /// Minimum enclosing sphere (Welzl, "Smallest enclosing disks (balls and
+    /// ellipsoids)"), in its iterative form. The points are visited in a
+    /// fixed pseudo-random order, which makes the expected running time
+    /// linear in their number; in the order given, sorted or otherwise
+    /// structured input can take quadratic time or worse. Returns `None` for
+    /// an empty slice.
+    pub fn welzl(points: &[Vector<T>]) -> Option<Self> {
+        let points = shuffled(points);
+        let first = points.first()?;
+        let mut sphere = Self::new(first.clone(), T::zero());
+        for i in 1..points.len() {
+            if sphere.encloses(&points[i]) {
+                continue;
+            }
+            sphere = Self::new(points[i].clone(), T::zero());
+            for j in 0..i {
+                if sphere.encloses(&points[j]) {
+                    continue;
+                }
+                sphere = Self::from_two(&points[i], &points[j]);
+                for k in 0..j {
+                    if sphere.encloses(&points[k]) {
+                        continue;
+                    }
+                    sphere = Self::from_three(&points[i], &points[j], &points[k]);
+                    for l in 0..k {
+                        if sphere.encloses(&points[l]) {
+                            continue;
+                        }
+                        sphere = Self::from_four(&points[i], &points[j], &points[k], &points[l]);
+                    }
+                }
+            }
+        }
+        Some(sphere)
+    }
+
+    // Containment with a little slack for points that lie on the boundary
+    // up to rounding.
+    fn encloses(&self, point: &Vector<T>) -> bool {
+        let slack = T::one() + T::from_f64(64.0) * T::epsilon();
+        (point - &self.center).magnitude() <= self.radius * slack
+    }
+
+    fn from_two(a: &Vector<T>, b: &Vector<T>) -> Self {
+        let center = (a + b) / T::from_f64(2.0);
+        let radius = (a - &center).magnitude();
+        Self { center, radius }
+    }
+
+    // Smallest sphere through three points, the circumcircle unless they are
+    // collinear.
+    fn from_three(a: &Vector<T>, b: &Vector<T>, c: &Vector<T>) -> Self {
+        let triangle = Triangle::new(a.clone(), b.clone(), c.clone());
+        if let Some(center) = triangle.circumcenter() {
+            let radius = (a - &center).magnitude();
+            return Self { center, radius };
+        }
+        // Collinear, the two points farthest apart span the sphere.
+        [Self::from_two(a, c), Self::from_two(b, c)]
+            .into_iter()
+            .fold(Self::from_two(a, b), |best, s| {
+                if s.radius > best.radius {
+                    s
+                } else {
+                    best
+                }
+            })
+    }
+
+    // Sphere through four points. Coplanar points fall back to the smallest
+    // sphere through three of them that contains the fourth.
+    fn from_four(a: &Vector<T>, b: &Vector<T>, c: &Vector<T>, d: &Vector<T>) -> Self {
+        let ab = b - a;
+        let ac = c - a;
+        let ad = d - a;
+        let two = T::from_f64(2.0);
+        let system = Matrix::from_rows([
+            [two * ab.x, two * ab.y, two * ab.z],
+            [two * ac.x, two * ac.y, two * ac.z],
+            [two * ad.x, two * ad.y, two * ad.z],
+        ]);
+        let rhs = Vector::new(
+            ab.magnitude_squared(),
+            ac.magnitude_squared(),
+            ad.magnitude_squared(),
+        );
+        if let Some(offset) = system.solve(&rhs) {
+            let radius = offset.magnitude();
+            return Self {
+                center: a + offset,
+                radius,
+            };
+        }
+        [(a, b, c, d), (a, b, d, c), (a, c, d, b), (b, c, d, a)]
+            .into_iter()
+            .map(|(p, q, r, rest)| (Self::from_three(p, q, r), rest))
+            .filter(|(s, rest)| s.encloses(rest))
+            .map(|(s, _)| s)
+            .fold(None, |best: Option<Self>, s| match best {
+                Some(best) if best.radius <= s.radius => Some(best),
+                _ => Some(s),
+            })
+            .unwrap_or_else(|| Self::from_three(a, b, c))
+    }
+}
+
+// Copy of `points` in an order that does not depend on their arrangement,
+// shuffled by Fisher-Yates with a fixed xorshift generator so results are
+// reproducible.
+fn shuffled<T: Numeric>(points: &[Vector<T>]) -> Vec<Vector<T>> {
+    let mut points = points.to_vec();
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        points.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+    points
+}
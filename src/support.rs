@@ -0,0 +1,205 @@
+use crate::{
+    aabb::Aabb,
+    gjk::{self, Penetration, Proximity},
+    line::Segment,
+    numeric::{Numeric, Real},
+    obb::Obb,
+    sphere::Sphere,
+    triangle::Triangle,
+    vector::Vector,
+};
+
+/// Convex shape described by its support function, the point of the shape
+/// farthest along a given direction. This is all [`SupportMap::proximity`]
+/// and [`SupportMap::penetration`] need to know about a shape.
+pub trait SupportMap<T: Real> {
+    /// A point of the shape maximizing `point.dot(direction)`. The direction
+    /// need not be normalized and may be zero.
+    fn support(&self, direction: &Vector<T>) -> Vector<T>;
+
+    /// Distance and closest points to `other` by the GJK algorithm (Gilbert,
+    /// Johnson and Keerthi).
+    fn proximity<S: SupportMap<T> + ?Sized>(&self, other: &S) -> Proximity<T>
+    where
+        Self: Sized,
+    {
+        gjk::proximity(self, other)
+    }
+
+    /// Penetration depth, normal and contact points of two overlapping
+    /// shapes by the expanding polytope algorithm. Returns `None` if the
+    /// shapes are disjoint, the overlap is degenerate, e.g. for two flat
+    /// shapes, or the polytope does not converge. The latter happens for deep
+    /// overlaps of curved shapes with the origin of the Minkowski difference
+    /// near its center, such as two concentric spheres.
+    fn penetration<S: SupportMap<T> + ?Sized>(&self, other: &S) -> Option<Penetration<T>>
+    where
+        Self: Sized,
+    {
+        gjk::penetration(self, other)
+    }
+}
+
+/// Sphere swept along a segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capsule<T: Numeric> {
+    pub segment: Segment<T>,
+    pub radius: T,
+}
+impl<T: Numeric> Capsule<T> {
+    pub fn new(segment: Segment<T>, radius: T) -> Self {
+        Self { segment, radius }
+    }
+}
+
+/// Cylinder whose axis runs from the center of one cap to the other. With a
+/// zero-length axis the cap has no orientation, and the cylinder acts as a
+/// disc facing every direction, i.e. a sphere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cylinder<T: Numeric> {
+    pub axis: Segment<T>,
+    pub radius: T,
+}
+impl<T: Numeric> Cylinder<T> {
+    pub fn new(axis: Segment<T>, radius: T) -> Self {
+        Self { axis, radius }
+    }
+}
+
+/// Cone with a circular base of `radius` around `base`. If `apex` and `base`
+/// coincide the cone collapses to the apex.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cone<T: Numeric> {
+    pub apex: Vector<T>,
+    pub base: Vector<T>,
+    pub radius: T,
+}
+impl<T: Numeric> Cone<T> {
+    pub fn new(apex: Vector<T>, base: Vector<T>, radius: T) -> Self {
+        Self { apex, base, radius }
+    }
+}
+
+/// Convex hull of a point cloud, represented by the points themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull<T: Numeric> {
+    pub points: Vec<Vector<T>>,
+}
+impl<T: Numeric> ConvexHull<T> {
+    pub fn new(points: Vec<Vector<T>>) -> Self {
+        Self { points }
+    }
+}
+
+// Offset of length `radius` along `direction`, zero for a zero direction.
+fn radial<T: Real>(direction: &Vector<T>, radius: T) -> Vector<T> {
+    let magnitude = direction.magnitude();
+    if magnitude == T::zero() {
+        return Vector::default();
+    }
+    direction * (radius / magnitude)
+}
+
+fn farthest<'a, T: Real + 'a>(
+    points: impl IntoIterator<Item = &'a Vector<T>>,
+    direction: &Vector<T>,
+) -> Vector<T> {
+    points
+        .into_iter()
+        .map(|p| (p, p.dot(direction)))
+        .reduce(|best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+        .map(|(p, _)| p.clone())
+        .unwrap_or_default()
+}
+
+impl<T: Real> SupportMap<T> for Sphere<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        &self.center + radial(direction, self.radius)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Aabb<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        let pick = |d: T, min: T, max: T| if d < T::zero() { min } else { max };
+        Vector::new(
+            pick(direction.x, self.min.x, self.max.x),
+            pick(direction.y, self.min.y, self.max.y),
+            pick(direction.z, self.min.z, self.max.z),
+        )
+    }
+}
+
+impl<T: Real> SupportMap<T> for Obb<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        let extents = [
+            self.half_extents.x,
+            self.half_extents.y,
+            self.half_extents.z,
+        ];
+        (0..3).fold(self.center.clone(), |point, i| {
+            let axis = self.axis(i);
+            if axis.dot(direction) < T::zero() {
+                point - axis * extents[i]
+            } else {
+                point + axis * extents[i]
+            }
+        })
+    }
+}
+
+impl<T: Real> SupportMap<T> for Segment<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        farthest([&self.start, &self.end], direction)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Triangle<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        farthest([&self.a, &self.b, &self.c], direction)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Capsule<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        self.segment.support(direction) + radial(direction, self.radius)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Cylinder<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        let axis = self.axis.direction();
+        let length_squared = axis.magnitude_squared();
+        if length_squared == T::zero() {
+            return &self.axis.start + radial(direction, self.radius);
+        }
+        let along = direction.dot(&axis) / length_squared;
+        let across = direction - &axis * along;
+        self.axis.support(direction) + radial(&across, self.radius)
+    }
+}
+
+impl<T: Real> SupportMap<T> for Cone<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        let axis = &self.apex - &self.base;
+        let length_squared = axis.magnitude_squared();
+        if length_squared == T::zero() {
+            return self.apex.clone();
+        }
+        let along = direction.dot(&axis) / length_squared;
+        let across = direction - &axis * along;
+        let rim = &self.base + radial(&across, self.radius);
+        farthest([&self.apex, &rim], direction)
+    }
+}
+
+impl<T: Real> SupportMap<T> for ConvexHull<T> {
+    fn support(&self, direction: &Vector<T>) -> Vector<T> {
+        farthest(&self.points, direction)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    matrix::Matrix,
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Singular value decomposition `a = u * diag(singular_values) * v^T`.
+///
+/// `u` and `v` are always proper rotations. Singular values are sorted by
+/// magnitude in descending order; the last one is negative when `a` contains
+/// a reflection.
+#[derive(Debug, Clone)]
+pub struct Svd<T: Numeric> {
+    pub u: Matrix<T>,
+    pub singular_values: Vector<T>,
+    pub v: Matrix<T>,
+}
+impl<T: Numeric> Svd<T> {
+    pub fn sigma(&self) -> Matrix<T> {
+        let zero = T::zero();
+        Matrix::new(
+            self.singular_values.x,
+            zero,
+            zero,
+            zero,
+            self.singular_values.y,
+            zero,
+            zero,
+            zero,
+            self.singular_values.z,
+        )
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Computes the singular value decomposition from the eigenvectors of
+    /// `a^T a`, completing `u` for rank deficient matrices.
+    pub fn svd(&self) -> Svd<T> {
+        let v = (self.transpose() * self).symmetric_eigen().vectors;
+        let av = [self * v.column(0), self * v.column(1), self * v.column(2)];
+
+        let scale = av[0].magnitude();
+        if scale == T::zero() {
+            return Svd {
+                u: v.clone(),
+                singular_values: Vector::default(),
+                v,
+            };
+        }
+        let threshold = scale * T::epsilon();
+        let u1 = &av[0] / scale;
+        let residual = &av[1] - &u1 * u1.dot(&av[1]);
+        let u2 = if residual.magnitude() > threshold {
+            residual.normalize()
+        } else {
+            u1.orthogonal()
+        };
+        let u3 = u1.cross(&u2);
+
+        Svd {
+            singular_values: Vector::new(scale, u2.dot(&av[1]), u3.dot(&av[2])),
+            u: Matrix::from_columns(&u1, &u2, &u3),
+            v,
+        }
+    }
+
+    /// Splits the matrix into `rotation * stretch` with a proper rotation and
+    /// a symmetric stretch. The stretch is positive semidefinite unless the
+    /// determinant is negative, in which case the reflection ends up in the
+    /// stretch as a negative eigenvalue, e.g. `diag(1, 1, -1)` is all
+    /// stretch and no rotation.
+    pub fn polar_decomposition(&self) -> (Matrix<T>, Matrix<T>) {
+        let svd = self.svd();
+        let rotation = &svd.u * svd.v.transpose();
+        let stretch = &svd.v * svd.sigma() * svd.v.transpose();
+        (rotation, stretch)
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    matrix::Matrix,
+    matrix4::Matrix4,
+    numeric::{Numeric, Real, Signed},
+    vector::Vector,
+};
+
+/// Translation, rotation and non-uniform scale, applied to points as
+/// `rotation * (scale * p) + translation`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transform<T: Numeric> {
+    pub translation: Vector<T>,
+    pub rotation: Matrix<T>,
+    pub scale: Vector<T>,
+}
+impl<T: Numeric> Transform<T> {
+    pub fn new(translation: Vector<T>, rotation: Matrix<T>, scale: Vector<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            translation: Vector::default(),
+            rotation: Matrix::unity(),
+            scale: Vector::scalar(T::one()),
+        }
+    }
+
+    pub fn from_translation(translation: Vector<T>) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Matrix<T>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vector<T>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// The rotation and scale part as a single matrix.
+    pub fn linear(&self) -> Matrix<T> {
+        let r = &self.rotation;
+        let s = &self.scale;
+        Matrix::new(
+            r.m11 * s.x,
+            r.m12 * s.y,
+            r.m13 * s.z,
+            r.m21 * s.x,
+            r.m22 * s.y,
+            r.m23 * s.z,
+            r.m31 * s.x,
+            r.m32 * s.y,
+            r.m33 * s.z,
+        )
+    }
+
+    pub fn matrix(&self) -> Matrix4<T> {
+        let mut m = Matrix4::from(self.linear());
+        m.m14 = self.translation.x;
+        m.m24 = self.translation.y;
+        m.m34 = self.translation.z;
+        m
+    }
+
+    pub fn transform_point(&self, p: &Vector<T>) -> Vector<T> {
+        &self.rotation * p.component_mul(&self.scale) + &self.translation
+    }
+
+    pub fn transform_vector(&self, v: &Vector<T>) -> Vector<T> {
+        &self.rotation * v.component_mul(&self.scale)
+    }
+}
+impl<T: Real> Transform<T> {
+    /// Applies `self` after `other`. Returns `None` if the result has shear,
+    /// which happens when `self` scales non-uniformly and `other` rotates
+    /// the axes off each other. Multiply the [`Transform::matrix`] of both in
+    /// that case.
+    pub fn compose(&self, other: &Self) -> Option<Self> {
+        // `self.scale` as seen from the frame of `other.rotation`. It is
+        // diagonal iff that rotation maps axes onto axes (or the scale is
+        // uniform), and then holds the permuted scale factors.
+        let r = &other.rotation;
+        let s = &self.scale;
+        let scaled = Matrix::new(
+            r.m11 * s.x,
+            r.m12 * s.x,
+            r.m13 * s.x,
+            r.m21 * s.y,
+            r.m22 * s.y,
+            r.m23 * s.y,
+            r.m31 * s.z,
+            r.m32 * s.z,
+            r.m33 * s.z,
+        );
+        let m = r.transpose() * scaled;
+        let largest = s.x.abs().max(s.y.abs()).max(s.z.abs());
+        let tolerance = T::from_f64(8.0) * T::epsilon() * largest;
+        let off_diagonal = [m.m12, m.m13, m.m21, m.m23, m.m31, m.m32];
+        if off_diagonal.iter().any(|e| e.abs() > tolerance) {
+            return None;
+        }
+        Some(Self {
+            translation: self.transform_point(&other.translation),
+            rotation: &self.rotation * &other.rotation,
+            scale: Vector::new(m.m11, m.m22, m.m33).component_mul(&other.scale),
+        })
+    }
+
+    /// Applies the inverse-transpose of the linear part and renormalizes, which
+    /// keeps normals perpendicular to transformed surfaces.
+    pub fn transform_normal(&self, n: &Vector<T>) -> Vector<T> {
+        (&self.rotation * n.component_div(&self.scale)).normalize()
+    }
+
+    /// Inverse of the transform. Returns `None` if the scale is zero or not
+    /// uniform, since the inverse of a rotation after a non-uniform scale is
+    /// not a `Transform`; [`Matrix4::inverse`] of [`Transform::matrix`]
+    /// inverts those.
+    pub fn inverse(&self) -> Option<Self> {
+        let uniform = self.scale.x == self.scale.y && self.scale.y == self.scale.z;
+        if !uniform || self.scale.x == T::zero() {
+            return None;
+        }
+        let rotation = self.rotation.transpose();
+        let scale = Vector::scalar(T::one()).component_div(&self.scale);
+        let translation = -(&rotation * &self.translation).component_mul(&scale);
+        Some(Self {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+
+    /// Interpolates translation and scale linearly and rotation spherically.
+    pub fn interpolate(&self, other: &Self, t: T) -> Self {
+        Self {
+            translation: self.translation.lerp(&other.translation, t),
+            rotation: self.rotation.slerp(&other.rotation, t),
+            scale: self.scale.lerp(&other.scale, t),
+        }
+    }
+}
+
+/// Rigid transform made of a rotation followed by a translation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isometry<T: Numeric> {
+    pub translation: Vector<T>,
+    pub rotation: Matrix<T>,
+}
+impl<T: Numeric> Isometry<T> {
+    pub fn new(translation: Vector<T>, rotation: Matrix<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            translation: Vector::default(),
+            rotation: Matrix::unity(),
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix4<T> {
+        let mut m = Matrix4::from(&self.rotation);
+        m.m14 = self.translation.x;
+        m.m24 = self.translation.y;
+        m.m34 = self.translation.z;
+        m
+    }
+
+    pub fn transform_point(&self, p: &Vector<T>) -> Vector<T> {
+        &self.rotation * p + &self.translation
+    }
+
+    pub fn transform_vector(&self, v: &Vector<T>) -> Vector<T> {
+        &self.rotation * v
+    }
+
+    /// Rotations preserve angles, so normals transform like vectors.
+    pub fn transform_normal(&self, n: &Vector<T>) -> Vector<T> {
+        &self.rotation * n
+    }
+
+    /// Applies `self` after `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            translation: self.transform_point(&other.translation),
+            rotation: &self.rotation * &other.rotation,
+        }
+    }
+}
+impl<T: Signed> Isometry<T> {
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        let translation = -(&rotation * &self.translation);
+        Self {
+            translation,
+            rotation,
+        }
+    }
+}
+impl<T: Real> Isometry<T> {
+    pub fn interpolate(&self, other: &Self, t: T) -> Self {
+        Self {
+            translation: self.translation.lerp(&other.translation, t),
+            rotation: self.rotation.slerp(&other.rotation, t),
+        }
+    }
+}
+
+impl<T: Numeric> From<Isometry<T>> for Transform<T> {
+    fn from(isometry: Isometry<T>) -> Self {
+        Self {
+            translation: isometry.translation,
+            rotation: isometry.rotation,
+            scale: Vector::scalar(T::one()),
+        }
+    }
+}
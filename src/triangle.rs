@@ -0,0 +1,143 @@
+use crate::{
+    numeric::{Numeric, Real},
+    vector::Vector,
+};
+
+/// Triangle with corners `a`, `b`, `c`. The front face is the side from
+/// which the corners appear counter-clockwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle<T: Numeric> {
+    pub a: Vector<T>,
+    pub b: Vector<T>,
+    pub c: Vector<T>,
+}
+impl<T: Numeric> Triangle<T> {
+    pub fn new(a: Vector<T>, b: Vector<T>, c: Vector<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Normal scaled to twice the area of the triangle.
+    pub fn scaled_normal(&self) -> Vector<T> {
+        (&self.b - &self.a).cross(&(&self.c - &self.a))
+    }
+
+    pub fn centroid(&self) -> Vector<T> {
+        (&self.a + &self.b + &self.c) / (T::one() + T::one() + T::one())
+    }
+
+    /// Signed volume of the tetrahedron spanned by the triangle and the
+    /// origin. Summed over a closed mesh with consistent winding this gives
+    /// the enclosed volume.
+    pub fn signed_volume(&self) -> T {
+        let six = T::one() + T::one() + T::one() + T::one() + T::one() + T::one();
+        self.a.dot(&self.b.cross(&self.c)) / six
+    }
+}
+impl<T: Real> Triangle<T> {
+    pub fn normal(&self) -> Vector<T> {
+        self.scaled_normal().normalize()
+    }
+
+    pub fn area(&self) -> T {
+        self.scaled_normal().magnitude() / T::from_f64(2.0)
+    }
+
+    /// Barycentric coordinates `(u, v, w)` with `p = u * a + v * b + w * c`
+    /// for the projection of `point` onto the plane of the triangle. Returns
+    /// `None` for a degenerate triangle.
+    pub fn barycentric(&self, point: &Vector<T>) -> Option<Vector<T>> {
+        let v0 = &self.b - &self.a;
+        let v1 = &self.c - &self.a;
+        let v2 = point - &self.a;
+        let d00 = v0.dot(&v0);
+        let d01 = v0.dot(&v1);
+        let d11 = v1.dot(&v1);
+        let d20 = v2.dot(&v0);
+        let d21 = v2.dot(&v1);
+        let denominator = d00 * d11 - d01 * d01;
+        if denominator <= T::epsilon() * d00 * d11 {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Some(Vector::new(T::one() - v - w, v, w))
+    }
+
+    /// Whether the projection of `point` onto the plane of the triangle lies
+    /// inside it or on its boundary.
+    pub fn contains_point(&self, point: &Vector<T>) -> bool {
+        self.barycentric(point)
+            .is_some_and(|b| b.x >= T::zero() && b.y >= T::zero() && b.z >= T::zero())
+    }
+
+    /// Point of the triangle closest to `point`, found by classifying it into
+    /// the vertex, edge or face Voronoi region (Ericson, "Real-Time Collision
+    /// Detection", 5.1.5).
+    pub fn closest_point(&self, point: &Vector<T>) -> Vector<T> {
+        let (a, b, c) = (&self.a, &self.b, &self.c);
+        let zero = T::zero();
+        let ab = b - a;
+        let ac = c - a;
+        let ap = point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= zero && d2 <= zero {
+            return a.clone();
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= zero && d4 <= d3 {
+            return b.clone();
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= zero && d5 <= d6 {
+            return c.clone();
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = T::one() / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    /// Center of the circle through all three corners, `None` for a
+    /// degenerate triangle.
+    pub fn circumcenter(&self) -> Option<Vector<T>> {
+        let ab = &self.b - &self.a;
+        let ac = &self.c - &self.a;
+        let (ab_squared, ac_squared) = (ab.magnitude_squared(), ac.magnitude_squared());
+        let normal = ab.cross(&ac);
+        let denominator = T::from_f64(2.0) * normal.magnitude_squared();
+        if denominator <= T::epsilon() * ab_squared * ac_squared {
+            return None;
+        }
+        Some(&self.a + (ac * ab_squared - ab * ac_squared).cross(&normal) / denominator)
+    }
+
+    /// Center of the inscribed circle, the corners weighted by the lengths of
+    /// the opposite sides.
+    pub fn incenter(&self) -> Vector<T> {
+        let la = (&self.c - &self.b).magnitude();
+        let lb = (&self.a - &self.c).magnitude();
+        let lc = (&self.b - &self.a).magnitude();
+        (&self.a * la + &self.b * lb + &self.c * lc) / (la + lb + lc)
+    }
+}
@@ -1,9 +1,13 @@
 use std::{
     fmt::Debug,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::numeric::Numeric;
+use crate::{
+    numeric::{Checked, Numeric, Real, Signed},
+    vector2::Vector2,
+    vector4::Vector4,
+};
 
 #[derive(PartialEq, Eq)]
 pub struct Vector<T: Numeric> {
@@ -48,51 +52,158 @@ impl<T: Numeric> Vector<T> {
     pub fn magnitude_squared(&self) -> T {
         self.dot(self)
     }
-}
 
-impl<T: Numeric + Into<f32> + From<f32>> Vector<T> {
+    pub fn extend(&self, w: T) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    pub fn lerp(&self, rhs: &Self, t: T) -> Self {
+        Self {
+            x: self.x + (rhs.x - self.x) * t,
+            y: self.y + (rhs.y - self.y) * t,
+            z: self.z + (rhs.z - self.z) * t,
+        }
+    }
+
+    pub fn component_mul(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z,
+        }
+    }
+
+    pub fn component_div(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+            z: self.z / rhs.z,
+        }
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x < self.x { rhs.x } else { self.x },
+            y: if rhs.y < self.y { rhs.y } else { self.y },
+            z: if rhs.z < self.z { rhs.z } else { self.z },
+        }
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x > self.x { rhs.x } else { self.x },
+            y: if rhs.y > self.y { rhs.y } else { self.y },
+            z: if rhs.z > self.z { rhs.z } else { self.z },
+        }
+    }
+
     pub fn x_axis() -> Self {
         Self {
-            x: T::from(1.0),
-            y: T::from(0.0),
-            z: T::from(0.0),
+            x: T::one(),
+            y: T::zero(),
+            z: T::zero(),
         }
     }
 
     pub fn y_axis() -> Self {
         Self {
-            x: T::from(0.0),
-            y: T::from(1.0),
-            z: T::from(0.0),
+            x: T::zero(),
+            y: T::one(),
+            z: T::zero(),
         }
     }
 
     pub fn z_axis() -> Self {
         Self {
-            x: T::from(0.0),
-            y: T::from(0.0),
-            z: T::from(1.0),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::one(),
         }
     }
+}
 
-    pub fn magnitude(&self) -> f32 {
-        self.magnitude_squared().into().sqrt()
+impl<T: Signed> Vector<T> {
+    pub fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
     }
+}
 
-    pub fn angle(&self, rhs: &Self) -> f32 {
-        let dot = self.dot(rhs).into();
+impl<T: Checked> Vector<T> {
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+        })
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+        })
+    }
+
+    /// Multiplies every component by `scalar`.
+    pub fn checked_mul(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+            z: self.z.checked_mul(scalar)?,
+        })
+    }
+
+    /// Divides every component by `scalar`.
+    pub fn checked_div(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_div(scalar)?,
+            y: self.y.checked_div(scalar)?,
+            z: self.z.checked_div(scalar)?,
+        })
+    }
+}
+
+impl<T: Real> Vector<T> {
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn angle(&self, rhs: &Self) -> T {
+        let dot = self.dot(rhs);
         let mag = self.magnitude() * rhs.magnitude();
-        (dot / mag).acos()
+        (dot / mag).clamp(-T::one(), T::one()).acos()
     }
 
     pub fn normalize(&self) -> Self {
         let mag = self.magnitude();
         Self {
-            x: self.x / mag.into(),
-            y: self.y / mag.into(),
-            z: self.z / mag.into(),
+            x: self.x / mag,
+            y: self.y / mag,
+            z: self.z / mag,
         }
     }
+
+    /// Some unit vector perpendicular to this one, which must be non-zero.
+    pub fn orthogonal(&self) -> Self {
+        let a = self.abs();
+        let other = if a.x <= a.y && a.x <= a.z {
+            Self::x_axis()
+        } else if a.y <= a.z {
+            Self::y_axis()
+        } else {
+            Self::z_axis()
+        };
+        self.cross(&other).normalize()
+    }
 }
 
 impl<T: Numeric + Debug> Debug for Vector<T> {
@@ -111,6 +222,29 @@ impl<T: Numeric + Clone> Clone for Vector<T> {
     }
 }
 
+impl<T: Signed> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+impl<T: Signed> Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
 macro_rules! impl_vector_op {
     ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
         impl<T: Numeric> $op for Vector<T> {
@@ -177,6 +311,67 @@ macro_rules! impl_vector_op {
 impl_vector_op!(Add, AddAssign, add, add_assign);
 impl_vector_op!(Sub, SubAssign, sub, sub_assign);
 
+macro_rules! impl_swizzle {
+    ($($name:ident: $a:ident, $b:ident;)*) => {
+        impl<T: Numeric> Vector<T> {
+            $(
+                pub fn $name(&self) -> Vector2<T> {
+                    Vector2::new(self.$a, self.$b)
+                }
+            )*
+        }
+    };
+    ($($name:ident: $a:ident, $b:ident, $c:ident;)*) => {
+        impl<T: Numeric> Vector<T> {
+            $(
+                pub fn $name(&self) -> Vector<T> {
+                    Vector::new(self.$a, self.$b, self.$c)
+                }
+            )*
+        }
+    };
+}
+impl_swizzle!(
+    xx: x, x;
+    xy: x, y;
+    xz: x, z;
+    yx: y, x;
+    yy: y, y;
+    yz: y, z;
+    zx: z, x;
+    zy: z, y;
+    zz: z, z;
+);
+impl_swizzle!(
+    xxx: x, x, x;
+    xxy: x, x, y;
+    xxz: x, x, z;
+    xyx: x, y, x;
+    xyy: x, y, y;
+    xyz: x, y, z;
+    xzx: x, z, x;
+    xzy: x, z, y;
+    xzz: x, z, z;
+    yxx: y, x, x;
+    yxy: y, x, y;
+    yxz: y, x, z;
+    yyx: y, y, x;
+    yyy: y, y, y;
+    yyz: y, y, z;
+    yzx: y, z, x;
+    yzy: y, z, y;
+    yzz: y, z, z;
+    zxx: z, x, x;
+    zxy: z, x, y;
+    zxz: z, x, z;
+    zyx: z, y, x;
+    zyy: z, y, y;
+    zyz: z, y, z;
+    zzx: z, z, x;
+    zzy: z, z, y;
+    zzz: z, z, z;
+);
+
 macro_rules! impl_scalar_op {
     ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
         impl<T: Numeric> $op<T> for Vector<T> {
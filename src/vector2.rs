@@ -0,0 +1,284 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    numeric::{Checked, Numeric, Real, Signed},
+    vector::Vector,
+};
+
+#[derive(PartialEq, Eq)]
+pub struct Vector2<T: Numeric> {
+    pub x: T,
+    pub y: T,
+}
+impl<T: Numeric> Default for Vector2<T> {
+    fn default() -> Self {
+        Self {
+            x: T::default(),
+            y: T::default(),
+        }
+    }
+}
+impl<T: Numeric> Vector2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn scalar(scalar: T) -> Self {
+        Self {
+            x: scalar,
+            y: scalar,
+        }
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// The z component of the 3D cross product of both vectors lifted into
+    /// the xy plane, i.e. the perp-dot product.
+    pub fn perp_dot(&self, rhs: &Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// The vector rotated by 90 degrees counter-clockwise.
+    pub fn perp(&self) -> Self {
+        Self {
+            x: T::default() - self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn extend(&self, z: T) -> Vector<T> {
+        Vector::new(self.x, self.y, z)
+    }
+
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn lerp(&self, rhs: &Self, t: T) -> Self {
+        Self {
+            x: self.x + (rhs.x - self.x) * t,
+            y: self.y + (rhs.y - self.y) * t,
+        }
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x < self.x { rhs.x } else { self.x },
+            y: if rhs.y < self.y { rhs.y } else { self.y },
+        }
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x > self.x { rhs.x } else { self.x },
+            y: if rhs.y > self.y { rhs.y } else { self.y },
+        }
+    }
+
+    pub fn x_axis() -> Self {
+        Self {
+            x: T::one(),
+            y: T::zero(),
+        }
+    }
+
+    pub fn y_axis() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::one(),
+        }
+    }
+}
+
+impl<T: Signed> Vector2<T> {
+    pub fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+}
+
+impl<T: Checked> Vector2<T> {
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Multiplies every component by `scalar`.
+    pub fn checked_mul(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+        })
+    }
+
+    /// Divides every component by `scalar`.
+    pub fn checked_div(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_div(scalar)?,
+            y: self.y.checked_div(scalar)?,
+        })
+    }
+}
+
+impl<T: Real> Vector2<T> {
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn angle(&self, rhs: &Self) -> T {
+        let dot = self.dot(rhs);
+        let mag = self.magnitude() * rhs.magnitude();
+        (dot / mag).clamp(-T::one(), T::one()).acos()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let mag = self.magnitude();
+        Self {
+            x: self.x / mag,
+            y: self.y / mag,
+        }
+    }
+}
+
+impl<T: Numeric + Debug> Debug for Vector2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vector2 {{ x: {:?}, y: {:?} }}", self.x, self.y)
+    }
+}
+
+impl<T: Numeric + Clone> Clone for Vector2<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.x, self.y)
+    }
+}
+
+impl<T: Signed> Neg for Vector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+impl<T: Signed> Neg for &Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+macro_rules! impl_vector_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op for Vector2<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                }
+            }
+        }
+        impl<T: Numeric> $op<&Vector2<T>> for Vector2<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                Self {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                }
+            }
+        }
+        impl<T: Numeric> $op<Vector2<T>> for &Vector2<T> {
+            type Output = Vector2<T>;
+
+            fn $method(self, rhs: Vector2<T>) -> Self::Output {
+                Vector2 {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                }
+            }
+        }
+        impl<T: Numeric> $op for &Vector2<T> {
+            type Output = Vector2<T>;
+
+            fn $method(self, rhs: &Vector2<T>) -> Self::Output {
+                Vector2 {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign for Vector2<T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+            }
+        }
+        impl<T: Numeric> $op_assign<&Vector2<T>> for Vector2<T> {
+            fn $method_assign(&mut self, rhs: &Self) {
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+            }
+        }
+    };
+    () => {};
+}
+impl_vector_op!(Add, AddAssign, add, add_assign);
+impl_vector_op!(Sub, SubAssign, sub, sub_assign);
+
+macro_rules! impl_scalar_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op<T> for Vector2<T> {
+            type Output = Self;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Self {
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op<T> for &Vector2<T> {
+            type Output = Vector2<T>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Vector2 {
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign<T> for Vector2<T> {
+            fn $method_assign(&mut self, scalar: T) {
+                self.x = self.x.$method(scalar);
+                self.y = self.y.$method(scalar);
+            }
+        }
+    };
+    () => {};
+}
+impl_scalar_op!(Mul, MulAssign, mul, mul_assign);
+impl_scalar_op!(Div, DivAssign, div, div_assign);
@@ -0,0 +1,342 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    numeric::{Checked, Numeric, Real, Signed},
+    vector::Vector,
+};
+
+#[derive(PartialEq, Eq)]
+pub struct Vector4<T: Numeric> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+impl<T: Numeric> Default for Vector4<T> {
+    fn default() -> Self {
+        Self {
+            x: T::default(),
+            y: T::default(),
+            z: T::default(),
+            w: T::default(),
+        }
+    }
+}
+impl<T: Numeric> Vector4<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn scalar(scalar: T) -> Self {
+        Self {
+            x: scalar,
+            y: scalar,
+            z: scalar,
+            w: scalar,
+        }
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    pub fn truncate(&self) -> Vector<T> {
+        Vector::new(self.x, self.y, self.z)
+    }
+
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn lerp(&self, rhs: &Self, t: T) -> Self {
+        Self {
+            x: self.x + (rhs.x - self.x) * t,
+            y: self.y + (rhs.y - self.y) * t,
+            z: self.z + (rhs.z - self.z) * t,
+            w: self.w + (rhs.w - self.w) * t,
+        }
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x < self.x { rhs.x } else { self.x },
+            y: if rhs.y < self.y { rhs.y } else { self.y },
+            z: if rhs.z < self.z { rhs.z } else { self.z },
+            w: if rhs.w < self.w { rhs.w } else { self.w },
+        }
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self {
+            x: if rhs.x > self.x { rhs.x } else { self.x },
+            y: if rhs.y > self.y { rhs.y } else { self.y },
+            z: if rhs.z > self.z { rhs.z } else { self.z },
+            w: if rhs.w > self.w { rhs.w } else { self.w },
+        }
+    }
+
+    pub fn x_axis() -> Self {
+        Self {
+            x: T::one(),
+            y: T::zero(),
+            z: T::zero(),
+            w: T::zero(),
+        }
+    }
+
+    pub fn y_axis() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::one(),
+            z: T::zero(),
+            w: T::zero(),
+        }
+    }
+
+    pub fn z_axis() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::one(),
+            w: T::zero(),
+        }
+    }
+
+    pub fn w_axis() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+            w: T::one(),
+        }
+    }
+}
+
+impl<T: Signed> Vector4<T> {
+    pub fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs(),
+        }
+    }
+}
+
+impl<T: Checked> Vector4<T> {
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+            w: self.w.checked_add(rhs.w)?,
+        })
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+            w: self.w.checked_sub(rhs.w)?,
+        })
+    }
+
+    /// Multiplies every component by `scalar`.
+    pub fn checked_mul(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+            z: self.z.checked_mul(scalar)?,
+            w: self.w.checked_mul(scalar)?,
+        })
+    }
+
+    /// Divides every component by `scalar`.
+    pub fn checked_div(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_div(scalar)?,
+            y: self.y.checked_div(scalar)?,
+            z: self.z.checked_div(scalar)?,
+            w: self.w.checked_div(scalar)?,
+        })
+    }
+}
+
+impl<T: Real> Vector4<T> {
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn angle(&self, rhs: &Self) -> T {
+        let dot = self.dot(rhs);
+        let mag = self.magnitude() * rhs.magnitude();
+        (dot / mag).clamp(-T::one(), T::one()).acos()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let mag = self.magnitude();
+        Self {
+            x: self.x / mag,
+            y: self.y / mag,
+            z: self.z / mag,
+            w: self.w / mag,
+        }
+    }
+}
+
+impl<T: Numeric + Debug> Debug for Vector4<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Vector4 {{ x: {:?}, y: {:?}, z: {:?}, w: {:?} }}",
+            self.x, self.y, self.z, self.w
+        )
+    }
+}
+
+impl<T: Numeric + Clone> Clone for Vector4<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.x, self.y, self.z, self.w)
+    }
+}
+
+impl<T: Signed> Neg for Vector4<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+impl<T: Signed> Neg for &Vector4<T> {
+    type Output = Vector4<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector4 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+macro_rules! impl_vector_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op for Vector4<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                    w: self.w.$method(rhs.w),
+                }
+            }
+        }
+        impl<T: Numeric> $op<&Vector4<T>> for Vector4<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                Self {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                    w: self.w.$method(rhs.w),
+                }
+            }
+        }
+        impl<T: Numeric> $op<Vector4<T>> for &Vector4<T> {
+            type Output = Vector4<T>;
+
+            fn $method(self, rhs: Vector4<T>) -> Self::Output {
+                Vector4 {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                    w: self.w.$method(rhs.w),
+                }
+            }
+        }
+        impl<T: Numeric> $op for &Vector4<T> {
+            type Output = Vector4<T>;
+
+            fn $method(self, rhs: &Vector4<T>) -> Self::Output {
+                Vector4 {
+                    x: self.x.$method(rhs.x),
+                    y: self.y.$method(rhs.y),
+                    z: self.z.$method(rhs.z),
+                    w: self.w.$method(rhs.w),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign for Vector4<T> {
+            fn $method_assign(&mut self, rhs: Self) {
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+                self.z = self.z.$method(rhs.z);
+                self.w = self.w.$method(rhs.w);
+            }
+        }
+        impl<T: Numeric> $op_assign<&Vector4<T>> for Vector4<T> {
+            fn $method_assign(&mut self, rhs: &Self) {
+                self.x = self.x.$method(rhs.x);
+                self.y = self.y.$method(rhs.y);
+                self.z = self.z.$method(rhs.z);
+                self.w = self.w.$method(rhs.w);
+            }
+        }
+    };
+    () => {};
+}
+impl_vector_op!(Add, AddAssign, add, add_assign);
+impl_vector_op!(Sub, SubAssign, sub, sub_assign);
+
+macro_rules! impl_scalar_op {
+    ($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+        impl<T: Numeric> $op<T> for Vector4<T> {
+            type Output = Self;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Self {
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                    z: self.z.$method(scalar),
+                    w: self.w.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op<T> for &Vector4<T> {
+            type Output = Vector4<T>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                Vector4 {
+                    x: self.x.$method(scalar),
+                    y: self.y.$method(scalar),
+                    z: self.z.$method(scalar),
+                    w: self.w.$method(scalar),
+                }
+            }
+        }
+        impl<T: Numeric> $op_assign<T> for Vector4<T> {
+            fn $method_assign(&mut self, scalar: T) {
+                self.x = self.x.$method(scalar);
+                self.y = self.y.$method(scalar);
+                self.z = self.z.$method(scalar);
+                self.w = self.w.$method(scalar);
+            }
+        }
+    };
+    () => {};
+}
+impl_scalar_op!(Mul, MulAssign, mul, mul_assign);
+impl_scalar_op!(Div, DivAssign, div, div_assign);
@@ -0,0 +1,105 @@
+use crate::{matrix4::Matrix4, numeric::Real, projection::Handedness, vector::Vector};
+
+impl<T: Real> Matrix4<T> {
+    /// World-to-camera transform for a camera at `eye` looking at `target`.
+    /// Returns `None` if `eye` and `target` coincide or `up` is parallel to
+    /// the view direction.
+    pub fn look_at(
+        eye: &Vector<T>,
+        target: &Vector<T>,
+        up: &Vector<T>,
+        handedness: Handedness,
+    ) -> Option<Self> {
+        Self::look_to(eye, &(target - eye), up, handedness)
+    }
+
+    /// World-to-camera transform for a camera at `eye` looking along `direction`.
+    pub fn look_to(
+        eye: &Vector<T>,
+        direction: &Vector<T>,
+        up: &Vector<T>,
+        handedness: Handedness,
+    ) -> Option<Self> {
+        let (x, y, z) = camera_axes(direction, up, handedness)?;
+        let zero = T::zero();
+        Some(Self::new(
+            x.x,
+            x.y,
+            x.z,
+            -x.dot(eye),
+            y.x,
+            y.y,
+            y.z,
+            -y.dot(eye),
+            z.x,
+            z.y,
+            z.z,
+            -z.dot(eye),
+            zero,
+            zero,
+            zero,
+            T::one(),
+        ))
+    }
+
+    /// Camera-to-world transform, the inverse of [`Matrix4::look_at`].
+    pub fn look_at_inverse(
+        eye: &Vector<T>,
+        target: &Vector<T>,
+        up: &Vector<T>,
+        handedness: Handedness,
+    ) -> Option<Self> {
+        Self::look_to_inverse(eye, &(target - eye), up, handedness)
+    }
+
+    /// Camera-to-world transform, the inverse of [`Matrix4::look_to`].
+    pub fn look_to_inverse(
+        eye: &Vector<T>,
+        direction: &Vector<T>,
+        up: &Vector<T>,
+        handedness: Handedness,
+    ) -> Option<Self> {
+        let (x, y, z) = camera_axes(direction, up, handedness)?;
+        let zero = T::zero();
+        Some(Self::new(
+            x.x,
+            y.x,
+            z.x,
+            eye.x,
+            x.y,
+            y.y,
+            z.y,
+            eye.y,
+            x.z,
+            y.z,
+            z.z,
+            eye.z,
+            zero,
+            zero,
+            zero,
+            T::one(),
+        ))
+    }
+}
+
+// Orthonormal camera basis expressed in world space. The camera z axis points
+// backwards for right-handed and forwards for left-handed view spaces.
+fn camera_axes<T: Real>(
+    direction: &Vector<T>,
+    up: &Vector<T>,
+    handedness: Handedness,
+) -> Option<(Vector<T>, Vector<T>, Vector<T>)> {
+    let direction_magnitude = direction.magnitude();
+    let up_magnitude = up.magnitude();
+    if direction_magnitude == T::zero() || up_magnitude == T::zero() {
+        return None;
+    }
+    let z = direction * (handedness.forward_sign::<T>() / direction_magnitude);
+    let side = up.cross(&z);
+    if side.magnitude() <= T::epsilon() * up_magnitude {
+        return None;
+    }
+    let x = side.normalize();
+    let y = z.cross(&x);
+    Some((x, y, z))
+}
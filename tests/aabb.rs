@@ -0,0 +1,88 @@
+use threed::{Aabb, Matrix, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+fn aabb() -> Aabb<f64> {
+    Aabb::new(Vector::new(-1.0, 0.5, 2.0), Vector::new(3.0, 1.5, 2.5))
+}
+
+fn corners(aabb: &Aabb<f64>) -> Vec<Vector<f64>> {
+    let (min, max) = (&aabb.min, &aabb.max);
+    (0..8)
+        .map(|i| {
+            Vector::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn transform_bounds_the_transformed_corners() {
+    let translation = Vector::new(1.0, -2.0, 0.5);
+    let matrices = [
+        Matrix::rotation(0.7, &Vector::new(1.0, 2.0, 2.0).normalize()),
+        Matrix::from_rows([[2.0, -1.0, 0.0], [0.5, 0.0, 3.0], [-1.0, 1.0, 1.0]]),
+        Matrix::new(-1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.5),
+    ];
+    for matrix in &matrices {
+        let transformed: Vec<_> = corners(&aabb())
+            .iter()
+            .map(|p| matrix * p + &translation)
+            .collect();
+        let expected = Aabb::from_points(&transformed).unwrap();
+
+        let actual = aabb().transform(matrix, &translation);
+        assert_close(&actual.min, &expected.min);
+        assert_close(&actual.max, &expected.max);
+    }
+}
+
+#[test]
+fn construction_and_queries() {
+    let points = [
+        Vector::new(3.0, 0.5, 2.5),
+        Vector::new(-1.0, 1.5, 2.0),
+        Vector::new(0.0, 1.0, 2.2),
+    ];
+    assert_eq!(Aabb::from_points(&points), Some(aabb()));
+    assert_eq!(Aabb::<f64>::from_points(&[]), None);
+
+    let a = aabb();
+    assert_close(&a.center(), &Vector::new(1.0, 1.0, 2.25));
+    assert_close(&a.extents(), &Vector::new(2.0, 0.5, 0.25));
+    assert_near(a.volume(), 2.0);
+    assert_near(a.surface_area(), 2.0 * (4.0 + 2.0 + 0.5));
+    assert!(a.contains(&Vector::new(3.0, 1.0, 2.0)));
+    assert!(!a.contains(&Vector::new(3.1, 1.0, 2.0)));
+
+    let outside = Vector::new(5.0, 1.0, 0.0);
+    assert_close(&a.closest_point(&outside), &Vector::new(3.0, 1.0, 2.0));
+    assert_near(a.distance_squared(&outside), 8.0);
+}
+
+#[test]
+fn union_and_intersection() {
+    let a = aabb();
+    let b = Aabb::new(Vector::new(2.0, 1.0, 0.0), Vector::new(4.0, 2.0, 2.2));
+
+    assert!(a.intersects(&b));
+    assert_eq!(
+        a.intersection(&b),
+        Some(Aabb::new(
+            Vector::new(2.0, 1.0, 2.0),
+            Vector::new(3.0, 1.5, 2.2)
+        ))
+    );
+    assert_eq!(
+        a.union(&b),
+        Aabb::new(Vector::new(-1.0, 0.5, 0.0), Vector::new(4.0, 2.0, 2.5))
+    );
+
+    let far = Aabb::new(Vector::new(5.0, 5.0, 5.0), Vector::new(6.0, 6.0, 6.0));
+    assert!(!a.intersects(&far));
+    assert_eq!(a.intersection(&far), None);
+}
@@ -0,0 +1,74 @@
+// Assertions shared by the integration tests. Not every test crate uses all
+// of them.
+#![allow(dead_code)]
+
+use threed::{Matrix, Matrix4, Quaternion, Vector};
+
+pub const TOLERANCE: f64 = 1e-12;
+
+pub fn assert_near(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < TOLERANCE,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+pub fn assert_close(actual: &Vector<f64>, expected: &Vector<f64>) {
+    assert_close_within(actual, expected, TOLERANCE);
+}
+
+pub fn assert_close_within(actual: &Vector<f64>, expected: &Vector<f64>, tolerance: f64) {
+    let diff = actual - expected;
+    assert!(
+        diff.magnitude() < tolerance,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+pub fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+    assert_matrix_close_within(actual, expected, TOLERANCE);
+}
+
+pub fn assert_matrix_close_within(actual: &Matrix<f64>, expected: &Matrix<f64>, tolerance: f64) {
+    let close = actual
+        .to_rows()
+        .iter()
+        .flatten()
+        .zip(expected.to_rows().iter().flatten())
+        .all(|(a, e)| (a - e).abs() < tolerance);
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+pub fn assert_matrix4_close(actual: &Matrix4<f64>, expected: &Matrix4<f64>) {
+    let d = actual - expected;
+    let entries = [
+        d.m11, d.m12, d.m13, d.m14, d.m21, d.m22, d.m23, d.m24, d.m31, d.m32, d.m33, d.m34, d.m41,
+        d.m42, d.m43, d.m44,
+    ];
+    assert!(
+        entries.iter().all(|e| e.abs() < TOLERANCE),
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+/// Orthonormal with determinant one.
+pub fn assert_rotation(m: &Matrix<f64>) {
+    assert_matrix_close(&(m.transpose() * m), &Matrix::unity());
+    assert!((m.determinant() - 1.0).abs() < TOLERANCE);
+}
+
+pub fn assert_quaternion_close(actual: &Quaternion<f64>, expected: &Quaternion<f64>) {
+    let diff = actual - expected;
+    assert!(
+        diff.magnitude() < TOLERANCE,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
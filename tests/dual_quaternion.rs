@@ -0,0 +1,114 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use threed::{DualQuaternion, Quaternion, Vector};
+
+mod common;
+use common::{assert_close, assert_quaternion_close};
+
+fn points() -> [Vector<f64>; 3] {
+    [
+        Vector::default(),
+        Vector::new(1.0, 2.0, 3.0),
+        Vector::new(-0.5, 0.25, 2.0),
+    ]
+}
+
+fn screw() -> DualQuaternion<f64> {
+    DualQuaternion::from_rotation_translation(
+        &Quaternion::from_axis_angle(1.1, &Vector::new(1.0, 2.0, 2.0).normalize()),
+        &Vector::new(1.0, -2.0, 3.0),
+    )
+}
+
+// Turn by `radians` around the z axis through (1, 0, 0).
+fn offset_turn(radians: f64) -> DualQuaternion<f64> {
+    let rotation = Quaternion::from_axis_angle(radians, &Vector::z_axis());
+    let center = Vector::x_axis();
+    let translation = &center - rotation.rotate(&center);
+    DualQuaternion::from_rotation_translation(&rotation, &translation)
+}
+
+fn assert_same_motion(actual: &DualQuaternion<f64>, expected: &DualQuaternion<f64>) {
+    for p in points() {
+        assert_close(&actual.transform_point(&p), &expected.transform_point(&p));
+    }
+}
+
+#[test]
+fn parts_round_trip() {
+    let dq = screw();
+    assert_close(&dq.translation(), &Vector::new(1.0, -2.0, 3.0));
+    assert_quaternion_close(
+        &dq.rotation(),
+        &Quaternion::from_axis_angle(1.1, &Vector::new(1.0, 2.0, 2.0).normalize()),
+    );
+    assert_same_motion(&(&dq * dq.conjugate()), &DualQuaternion::identity());
+}
+
+#[test]
+fn composition_applies_the_right_operand_first() {
+    let a = screw();
+    let b = offset_turn(FRAC_PI_2);
+
+    let composed = &a * &b;
+    for p in points() {
+        assert_close(
+            &composed.transform_point(&p),
+            &a.transform_point(&b.transform_point(&p)),
+        );
+    }
+}
+
+#[test]
+fn sclerp_hits_the_endpoints() {
+    let a = offset_turn(0.3);
+    let b = screw();
+
+    assert_same_motion(&a.sclerp(&b, 0.0), &a);
+    assert_same_motion(&a.sclerp(&b, 1.0), &b);
+    // The sign of a dual quaternion does not change the transform.
+    assert_same_motion(&a.sclerp(&-&b, 1.0), &b);
+}
+
+#[test]
+fn sclerp_midpoint_follows_the_screw() {
+    let identity = DualQuaternion::identity();
+
+    // Rotating about an offset axis stays on the circle around it, unlike
+    // interpolating the translation linearly.
+    let half = identity.sclerp(&offset_turn(FRAC_PI_2), 0.5);
+    assert_same_motion(&half, &offset_turn(FRAC_PI_4));
+    assert_close(
+        &half.transform_point(&Vector::default()),
+        &Vector::new(1.0 - FRAC_PI_4.cos(), -FRAC_PI_4.sin(), 0.0),
+    );
+
+    // A screw along its own axis moves half the distance for half the angle.
+    let axis = Vector::new(1.0, 2.0, 2.0).normalize();
+    let full = DualQuaternion::from_rotation_translation(
+        &Quaternion::from_axis_angle(1.2, &axis),
+        &(&axis * 3.0),
+    );
+    let expected = DualQuaternion::from_rotation_translation(
+        &Quaternion::from_axis_angle(0.6, &axis),
+        &(&axis * 1.5),
+    );
+    assert_same_motion(&identity.sclerp(&full, 0.5), &expected);
+}
+
+#[test]
+fn blend_flips_antipodal_inputs() {
+    let a = screw();
+    let b = offset_turn(0.4);
+
+    let blended = DualQuaternion::blend(&[(0.5, a.clone()), (0.5, b.clone())]).unwrap();
+    let flipped = DualQuaternion::blend(&[(0.5, a.clone()), (0.5, -&b)]).unwrap();
+    assert_same_motion(&flipped, &blended);
+
+    // Without flipping these would cancel out.
+    let same = DualQuaternion::blend(&[(0.5, a.clone()), (0.5, -&a)]).unwrap();
+    assert_same_motion(&same, &a);
+
+    assert!(DualQuaternion::<f64>::blend(&[]).is_none());
+    assert!(DualQuaternion::blend(&[(0.0, a)]).is_none());
+}
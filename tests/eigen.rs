@@ -0,0 +1,84 @@
+use threed::{Matrix, Vector};
+
+mod common;
+use common::assert_matrix_close;
+
+fn diagonal(values: &Vector<f64>) -> Matrix<f64> {
+    Matrix::new(values.x, 0.0, 0.0, 0.0, values.y, 0.0, 0.0, 0.0, values.z)
+}
+
+fn symmetric() -> Matrix<f64> {
+    Matrix::from_rows([[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]])
+}
+
+#[test]
+fn eigenvectors_reconstruct_the_matrix() {
+    let a = symmetric();
+    let eigen = a.symmetric_eigen();
+
+    let v = &eigen.vectors;
+    assert_matrix_close(&(v * diagonal(&eigen.values) * v.transpose()), &a);
+    assert_matrix_close(&(v.transpose() * v), &Matrix::unity());
+    assert!((v.determinant() - 1.0).abs() < 1e-12);
+    for i in 0..3 {
+        let column = v.column(i);
+        let value = [eigen.values.x, eigen.values.y, eigen.values.z][i];
+        assert!((&a * &column - &column * value).magnitude() < 1e-12);
+    }
+}
+
+#[test]
+fn eigenvalues_are_sorted_descending() {
+    let a = diagonal(&Vector::new(-1.0, 5.0, 2.0));
+    let eigen = a.symmetric_eigen();
+
+    assert_eq!(eigen.values, Vector::new(5.0, 2.0, -1.0));
+    assert!((eigen.vectors.determinant() - 1.0).abs() < 1e-12);
+
+    let values = symmetric().symmetric_eigen().values;
+    assert!(values.x >= values.y && values.y >= values.z);
+}
+
+#[test]
+fn only_the_upper_triangle_is_read() {
+    let mut rows = symmetric().to_rows();
+    rows[1][0] = 100.0;
+    rows[2][0] = f64::NAN;
+    rows[2][1] = -7.0;
+
+    let eigen = Matrix::from_rows(rows).symmetric_eigen();
+    let expected = symmetric().symmetric_eigen();
+    assert!((&eigen.values - &expected.values).magnitude() < 1e-12);
+}
+
+#[test]
+fn sweeps_and_residual_report_convergence() {
+    let a = symmetric();
+    let eigen = a.symmetric_eigen();
+    assert!(eigen.converged);
+    assert!(eigen.sweeps > 0 && eigen.sweeps <= 32);
+    let norm = a
+        .to_rows()
+        .iter()
+        .flatten()
+        .map(|e| e * e)
+        .sum::<f64>()
+        .sqrt();
+    assert!(eigen.residual <= f64::EPSILON * norm);
+
+    // A diagonal matrix needs no sweep at all.
+    let eigen = diagonal(&Vector::new(3.0, 2.0, 1.0)).symmetric_eigen();
+    assert!(eigen.converged);
+    assert_eq!(eigen.sweeps, 0);
+    assert_eq!(eigen.residual, 0.0);
+}
+
+#[test]
+fn zero_sweeps_do_not_converge() {
+    let eigen = symmetric().symmetric_eigen_with(0, f64::EPSILON);
+
+    assert!(!eigen.converged);
+    assert_eq!(eigen.sweeps, 0);
+    // The untouched off-diagonal part of `symmetric`.
+    assert!((eigen.residual - 10.0_f64.sqrt()).abs() < 1e-12);
+}
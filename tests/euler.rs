@@ -0,0 +1,89 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{EulerAngles, EulerFrame, EulerOrder, Matrix, Vector};
+
+mod common;
+use common::assert_matrix_close_within;
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn is_tait_bryan(order: EulerOrder) -> bool {
+    !matches!(
+        order,
+        EulerOrder::XYX
+            | EulerOrder::XZX
+            | EulerOrder::YXY
+            | EulerOrder::YZY
+            | EulerOrder::ZXZ
+            | EulerOrder::ZYZ
+    )
+}
+
+#[test]
+fn angles_round_trip_for_every_order_and_frame() {
+    let samples = [(0.3, 0.4, -1.1), (-2.5, -0.2, 0.7), (1.9, 1.2, 3.0)];
+    for order in EulerOrder::ALL {
+        for frame in FRAMES {
+            for (first, second, third) in samples {
+                let second = if is_tait_bryan(order) {
+                    second
+                } else {
+                    second + FRAC_PI_2
+                };
+                let angles = EulerAngles::new(first, second, third, order, frame);
+                let extracted = EulerAngles::from_matrix(&angles.to_matrix(), order, frame);
+
+                assert_eq!(extracted.order, order);
+                assert_eq!(extracted.frame, frame);
+                for (a, b) in [
+                    (extracted.first, first),
+                    (extracted.second, second),
+                    (extracted.third, third),
+                ] {
+                    assert!(
+                        (a - b).abs() < 1e-9,
+                        "{:?} {:?}: expected {:?}, got {:?}",
+                        order,
+                        frame,
+                        angles,
+                        extracted
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock_still_reproduces_the_rotation() {
+    for order in EulerOrder::ALL {
+        for frame in FRAMES {
+            for second in [0.0, FRAC_PI_2, -FRAC_PI_2, std::f64::consts::PI] {
+                let angles = EulerAngles::new(0.4, second, -0.9, order, frame);
+                let matrix = angles.to_matrix();
+                let extracted = EulerAngles::from_matrix(&matrix, order, frame);
+
+                assert_matrix_close_within(&extracted.to_matrix(), &matrix, 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn intrinsic_is_extrinsic_in_reverse() {
+    let intrinsic = EulerAngles::new(0.3, -0.6, 1.2, EulerOrder::ZYX, EulerFrame::Intrinsic);
+    let extrinsic = EulerAngles::new(1.2, -0.6, 0.3, EulerOrder::XYZ, EulerFrame::Extrinsic);
+
+    assert_matrix_close_within(&intrinsic.to_matrix(), &extrinsic.to_matrix(), 1e-9);
+}
+
+#[test]
+fn yaw_pitch_roll_matches_axis_rotations() {
+    let (yaw, pitch, roll) = (0.8, -0.3, 0.5);
+    let angles = EulerAngles::new(yaw, pitch, roll, EulerOrder::ZYX, EulerFrame::Intrinsic);
+    let expected = Matrix::rotation(yaw, &Vector::z_axis())
+        * Matrix::rotation(pitch, &Vector::y_axis())
+        * Matrix::rotation(roll, &Vector::x_axis());
+
+    assert_matrix_close_within(&Matrix::from(&angles), &expected, 1e-9);
+}
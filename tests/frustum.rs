@@ -0,0 +1,170 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use threed::{
+    Aabb, Containment, DepthRange, Frustum, Handedness, Matrix, Matrix4, Obb, Plane, Sphere, Vector,
+};
+
+mod common;
+use common::{assert_close, assert_near};
+
+const DEPTHS: [DepthRange; 2] = [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne];
+const HANDS: [Handedness; 2] = [Handedness::Right, Handedness::Left];
+
+fn eye() -> Vector<f64> {
+    Vector::new(1.0, 2.0, -3.0)
+}
+
+fn direction() -> Vector<f64> {
+    Vector::new(0.5, -0.2, 1.0)
+}
+
+fn camera(handedness: Handedness) -> Frustum<f64> {
+    Frustum::from_camera(
+        &eye(),
+        &direction(),
+        &Vector::y_axis(),
+        1.0,
+        1.5,
+        0.5,
+        20.0,
+        handedness,
+    )
+    .unwrap()
+}
+
+fn view_projection(depth: DepthRange, handedness: Handedness, reversed: bool) -> Matrix4<f64> {
+    let projection = if reversed {
+        Matrix4::perspective_reversed_z(1.0, 1.5, 0.5, 20.0, depth, handedness)
+    } else {
+        Matrix4::perspective(1.0, 1.5, 0.5, 20.0, depth, handedness)
+    };
+    projection * Matrix4::look_to(&eye(), &direction(), &Vector::y_axis(), handedness).unwrap()
+}
+
+fn assert_plane_close(actual: &Plane<f64>, expected: &Plane<f64>) {
+    assert_close(&actual.normal, &expected.normal);
+    assert_near(actual.offset, expected.offset);
+}
+
+// Looks down -z from the origin with a 90 degree field of view, so the side
+// planes are x = ±z and y = ±z.
+fn square() -> Frustum<f64> {
+    Frustum::from_camera(
+        &Vector::default(),
+        &Vector::new(0.0, 0.0, -1.0),
+        &Vector::y_axis(),
+        FRAC_PI_2,
+        1.0,
+        1.0,
+        10.0,
+        Handedness::Right,
+    )
+    .unwrap()
+}
+
+#[test]
+fn from_matrix_agrees_with_from_camera() {
+    for handedness in HANDS {
+        let expected = camera(handedness);
+        for depth in DEPTHS {
+            let actual = Frustum::from_matrix(&view_projection(depth, handedness, false), depth);
+            for (a, e) in actual.planes().iter().zip(expected.planes()) {
+                assert_plane_close(a, e);
+            }
+        }
+    }
+}
+
+#[test]
+fn reversed_z_swaps_near_and_far() {
+    for handedness in HANDS {
+        let expected = camera(handedness);
+        for depth in DEPTHS {
+            let actual = Frustum::from_matrix(&view_projection(depth, handedness, true), depth);
+            assert_plane_close(&actual.near, &expected.far);
+            assert_plane_close(&actual.far, &expected.near);
+            assert_plane_close(&actual.left, &expected.left);
+            assert_plane_close(&actual.top, &expected.top);
+
+            let corners = actual.corners().unwrap();
+            let expected_corners = expected.corners().unwrap();
+            assert_close(&corners[0], &expected_corners[4]);
+            assert_close(&corners[7], &expected_corners[3]);
+        }
+    }
+}
+
+#[test]
+fn corners_lie_on_the_planes() {
+    let corners = square().corners().unwrap();
+    assert_close(&corners[0], &Vector::new(-1.0, -1.0, -1.0));
+    assert_close(&corners[3], &Vector::new(1.0, 1.0, -1.0));
+    assert_close(&corners[4], &Vector::new(-10.0, -10.0, -10.0));
+    assert_close(&corners[7], &Vector::new(10.0, 10.0, -10.0));
+
+    let infinite = Matrix4::perspective_infinite(
+        FRAC_PI_2,
+        1.0,
+        1.0,
+        DepthRange::ZeroToOne,
+        Handedness::Right,
+    );
+    let frustum = Frustum::from_matrix(&infinite, DepthRange::ZeroToOne);
+    assert_eq!(frustum.corners(), None);
+    assert!(frustum.contains_point(&Vector::new(0.0, 0.0, -1e9)));
+}
+
+#[test]
+fn classifies_points() {
+    let frustum = square();
+    assert!(frustum.contains_point(&Vector::new(0.0, 0.0, -5.0)));
+    assert!(frustum.contains_point(&Vector::new(4.9, -4.9, -5.0)));
+    assert!(!frustum.contains_point(&Vector::new(5.1, 0.0, -5.0)));
+    assert!(!frustum.contains_point(&Vector::new(0.0, 0.0, -0.5)));
+    assert!(!frustum.contains_point(&Vector::new(0.0, 0.0, -10.5)));
+}
+
+#[test]
+fn classifies_spheres() {
+    let frustum = square();
+    let classify = |z: f64| frustum.classify_sphere(&Sphere::new(Vector::new(0.0, 0.0, z), 1.0));
+    assert_eq!(classify(-5.0), Containment::Inside);
+    assert_eq!(classify(-10.5), Containment::Intersecting);
+    assert_eq!(classify(-0.5), Containment::Intersecting);
+    assert_eq!(classify(-11.5), Containment::Outside);
+    assert_eq!(
+        frustum.classify_sphere(&Sphere::new(Vector::new(7.0, 0.0, -5.0), 1.0)),
+        Containment::Outside
+    );
+}
+
+#[test]
+fn classifies_aabbs() {
+    let frustum = square();
+    let classify = |center: Vector<f64>| {
+        let half = Vector::scalar(0.5);
+        frustum.classify_aabb(&Aabb::new(&center - &half, &center + &half))
+    };
+    assert_eq!(classify(Vector::new(0.0, 0.0, -5.0)), Containment::Inside);
+    assert_eq!(
+        classify(Vector::new(5.0, 0.0, -5.0)),
+        Containment::Intersecting
+    );
+    assert_eq!(classify(Vector::new(6.5, 0.0, -5.0)), Containment::Outside);
+}
+
+#[test]
+fn classifies_obbs() {
+    let frustum = square();
+    let classify = |center: Vector<f64>| {
+        let rotation = Matrix::rotation(FRAC_PI_4, &Vector::z_axis());
+        frustum.classify_obb(&Obb::new(center, Vector::scalar(0.5), rotation))
+    };
+    assert_eq!(classify(Vector::new(0.0, 0.0, -5.0)), Containment::Inside);
+    // Rotated, the box reaches 0.85 towards the right plane.
+    assert_eq!(
+        classify(Vector::new(5.5, 0.0, -5.0)),
+        Containment::Intersecting
+    );
+    assert_eq!(classify(Vector::new(7.0, 0.0, -5.0)), Containment::Outside);
+}
@@ -0,0 +1,130 @@
+use threed::{Aabb, Cone, Cylinder, Proximity, Segment, Sphere, SupportMap, Triangle, Vector};
+
+mod common;
+use common::{assert_close, assert_close_within, assert_near};
+
+// Bound on the depth error documented on `Penetration::depth`.
+fn epa_tolerance(depth: f64) -> f64 {
+    f64::EPSILON.powf(0.25) * depth.max(1.0)
+}
+
+#[test]
+fn sphere_distance_matches_exact_value() {
+    let a: Sphere<f64> = Sphere::new(Vector::new(0.0, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Vector::new(3.0, 4.0, 0.0), 1.5);
+
+    match a.proximity(&b) {
+        Proximity::Disjoint {
+            distance,
+            point_a,
+            point_b,
+        } => {
+            assert!((distance - 2.5).abs() < 1e-9, "distance {}", distance);
+            assert_close_within(&point_a, &Vector::new(0.6, 0.8, 0.0), 1e-4);
+            assert_close_within(&point_b, &Vector::new(2.1, 2.8, 0.0), 1e-4);
+        }
+        Proximity::Intersecting => panic!("spheres are apart"),
+    }
+    assert!(a.penetration(&b).is_none());
+}
+
+#[test]
+fn box_penetration_takes_the_shallowest_axis() {
+    let a = Aabb::new(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 2.0, 2.0));
+    let b = Aabb::new(Vector::new(1.5, 0.5, 0.2), Vector::new(3.5, 2.5, 2.2));
+
+    assert_eq!(a.proximity(&b), Proximity::Intersecting);
+    let penetration = a.penetration(&b).unwrap();
+    assert_near(penetration.depth, 0.5);
+    assert_close(&penetration.normal, &Vector::x_axis());
+    assert_near(penetration.point_a.x, 2.0);
+    assert_near(penetration.point_b.x, 1.5);
+    assert_close(
+        &penetration.point_b,
+        &(&penetration.point_a - &penetration.normal * penetration.depth),
+    );
+}
+
+#[test]
+fn touching_boxes_intersect_with_zero_depth() {
+    let a = Aabb::new(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 2.0, 2.0));
+    let b = Aabb::new(Vector::new(2.0, 0.5, 0.2), Vector::new(4.0, 2.5, 2.2));
+
+    assert_eq!(a.proximity(&b), Proximity::Intersecting);
+    let penetration = a.penetration(&b).unwrap();
+    assert_near(penetration.depth, 0.0);
+    assert_close(&penetration.normal, &Vector::x_axis());
+}
+
+#[test]
+fn flat_overlap_has_no_penetration() {
+    let a = Triangle::new(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0),
+        Vector::new(0.0, 2.0, 0.0),
+    );
+    let b = Triangle::new(
+        Vector::new(0.5, 0.5, 0.0),
+        Vector::new(3.0, 0.5, 0.0),
+        Vector::new(0.5, 3.0, 0.0),
+    );
+
+    assert_eq!(a.proximity(&b), Proximity::Intersecting);
+    assert!(a.penetration(&b).is_none());
+}
+
+#[test]
+fn deep_overlap_is_accurate_or_none() {
+    let unit: Sphere<f64> = Sphere::new(Vector::new(0.0, 0.0, 0.0), 1.0);
+
+    // Every face of the polytope is about equally close to the origin, so
+    // EPA cannot refine it within its iteration budget.
+    let concentric = Sphere::new(Vector::new(0.0, 0.0, 0.0), 1.0);
+    assert!(unit.penetration(&concentric).is_none());
+
+    let offset = Sphere::new(Vector::new(0.1, 0.0, 0.0), 1.0);
+    let penetration = unit.penetration(&offset).unwrap();
+    let error = 1.9 - penetration.depth;
+    assert!(
+        (0.0..=epa_tolerance(1.9)).contains(&error),
+        "depth {}",
+        penetration.depth
+    );
+    // Facet normals of the polytope only approximate the curved surface.
+    assert_close_within(&penetration.normal, &Vector::x_axis(), 5e-2);
+
+    let cylinder: Cylinder<f64> = Cylinder::new(
+        Segment::new(Vector::new(0.0, 0.0, -2.0), Vector::new(0.0, 0.0, 2.0)),
+        1.0,
+    );
+    let inner = Sphere::new(Vector::new(0.0, 0.0, 0.0), 0.3);
+    let penetration = cylinder.penetration(&inner).unwrap();
+    let error = 1.3 - penetration.depth;
+    assert!(
+        (0.0..=epa_tolerance(1.3)).contains(&error),
+        "depth {}",
+        penetration.depth
+    );
+    assert!(penetration.normal.z.abs() < 5e-2);
+}
+
+#[test]
+fn zero_length_axes_have_finite_support() {
+    let center = Vector::new(1.0, 2.0, 3.0);
+    let direction = Vector::new(0.0, 3.0, 4.0);
+
+    let flat = Cylinder::new(Segment::new(center.clone(), center.clone()), 2.0);
+    assert_close(&flat.support(&direction), &Vector::new(1.0, 3.2, 4.6));
+    assert_close(&flat.support(&Vector::default()), &center);
+
+    let cone = Cone::new(center.clone(), center.clone(), 2.0);
+    assert_close(&cone.support(&direction), &center);
+
+    let sphere = Sphere::new(Vector::new(1.0, 2.0, 8.0), 1.0);
+    match flat.proximity(&sphere) {
+        Proximity::Disjoint { distance, .. } => {
+            assert!((distance - 2.0).abs() < 1e-7, "distance {}", distance)
+        }
+        Proximity::Intersecting => panic!("shapes are apart"),
+    }
+}
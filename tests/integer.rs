@@ -0,0 +1,65 @@
+use threed::{Matrix, Vector, Vector2, Vector4};
+
+#[test]
+fn integer_vectors_have_axes_and_identities() {
+    let axes = [Vector::<i32>::x_axis(), Vector::y_axis(), Vector::z_axis()];
+    assert_eq!(axes[0].cross(&axes[1]), axes[2]);
+    assert_eq!(
+        axes.iter().fold(Vector::default(), |sum, axis| sum + axis),
+        Vector::scalar(1)
+    );
+    assert_eq!(Vector::<u8>::z_axis(), Vector::new(0, 0, 1));
+    assert_eq!(Vector2::<i64>::y_axis(), Vector2::new(0, 1));
+    assert_eq!(Vector4::<usize>::w_axis(), Vector4::new(0, 0, 0, 1));
+}
+
+#[test]
+fn integer_vectors_support_abs_min_max_and_negation() {
+    let a = Vector::new(-3, 4, 0);
+    let b = Vector::new(2, -5, 1);
+
+    assert_eq!(a.abs(), Vector::new(3, 4, 0));
+    assert_eq!(a.min(&b), Vector::new(-3, -5, 0));
+    assert_eq!(a.max(&b), Vector::new(2, 4, 1));
+    assert_eq!(-&a, Vector::new(3, -4, 0));
+    assert_eq!(a.dot(&b), -26);
+    assert_eq!(a.magnitude_squared(), 25);
+    assert_eq!(a.component_mul(&b), Vector::new(-6, -20, 0));
+}
+
+#[test]
+fn integer_matrices_have_unity_and_exact_products() {
+    let unity = Matrix::<i32>::unity();
+    let m = Matrix::new(1, 2, 0, -1, 3, 4, 2, 0, 1);
+    let v = Vector::new(1, -1, 2);
+
+    assert_eq!(&unity * &m, m);
+    assert_eq!(&m * &unity, m);
+    assert_eq!(&unity * &v, v);
+    assert_eq!(&m * &v, Vector::new(-1, 4, 4));
+    assert_eq!(m.determinant(), 21);
+    assert_eq!(m.transpose().transpose(), m);
+}
+
+#[test]
+fn checked_operations_report_overflow() {
+    let big = Vector::new(i32::MAX, 0, 1);
+
+    assert_eq!(big.checked_add(&Vector::x_axis()), None);
+    assert_eq!(
+        big.checked_sub(&Vector::x_axis()),
+        Some(Vector::new(i32::MAX - 1, 0, 1))
+    );
+    assert_eq!(big.checked_mul(2), None);
+    assert_eq!(
+        Vector::new(4, -6, 8).checked_div(2),
+        Some(Vector::new(2, -3, 4))
+    );
+    assert_eq!(Vector::new(4, -6, 8).checked_div(0), None);
+
+    assert_eq!(Vector2::new(0u8, 1).checked_sub(&Vector2::new(1, 0)), None);
+    assert_eq!(
+        Vector4::new(1u16, 2, 3, 4).checked_mul(3),
+        Some(Vector4::new(3, 6, 9, 12))
+    );
+}
@@ -0,0 +1,90 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use threed::{Matrix, Quaternion, Vector};
+
+mod common;
+use common::{assert_close, assert_matrix_close, assert_near, assert_quaternion_close};
+
+fn angle_between(a: &Quaternion<f64>, b: &Quaternion<f64>) -> f64 {
+    2.0 * a.dot(b).abs().min(1.0).acos()
+}
+
+#[test]
+fn vector_lerp_hits_endpoints_and_extrapolates() {
+    let a = Vector::new(1.0, 2.0, 3.0);
+    let b = Vector::new(-1.0, 0.0, 5.0);
+
+    assert_close(&a.lerp(&b, 0.0), &a);
+    assert_close(&a.lerp(&b, 1.0), &b);
+    assert_close(&a.lerp(&b, 0.5), &Vector::new(0.0, 1.0, 4.0));
+    assert_close(&a.lerp(&b, 2.0), &Vector::new(-3.0, -2.0, 7.0));
+}
+
+#[test]
+fn slerp_moves_at_constant_angular_speed() {
+    let a = Quaternion::from_axis_angle(0.2, &Vector::z_axis());
+    let b = Quaternion::from_axis_angle(2.2, &Vector::z_axis());
+
+    assert_quaternion_close(&a.slerp(&b, 0.0), &a);
+    assert_quaternion_close(&a.slerp(&b, 1.0), &b);
+    for t in [0.1, 0.25, 0.5, 0.9] {
+        let q = a.slerp(&b, t);
+        assert_near(q.magnitude(), 1.0);
+        assert_near(angle_between(&a, &q), 2.0 * t);
+        assert_quaternion_close(
+            &q,
+            &Quaternion::from_axis_angle(0.2 + 2.0 * t, &Vector::z_axis()),
+        );
+    }
+}
+
+#[test]
+fn interpolation_takes_the_shortest_path() {
+    let a = Quaternion::from_axis_angle(0.0, &Vector::x_axis());
+    // 270 degrees one way is 90 degrees the other.
+    let b = Quaternion::from_axis_angle(1.5 * PI, &Vector::x_axis());
+    let expected = Quaternion::from_axis_angle(-FRAC_PI_2 / 2.0, &Vector::x_axis());
+
+    assert_quaternion_close(&a.slerp(&b, 0.5), &expected);
+    assert_quaternion_close(&a.nlerp(&b, 0.5), &expected);
+    // The endpoint is the same rotation, possibly with flipped sign.
+    assert_near(angle_between(&a.slerp(&b, 1.0), &b), 0.0);
+}
+
+#[test]
+fn nlerp_is_normalized_and_agrees_at_the_midpoint() {
+    let a = Quaternion::from_axis_angle(0.3, &Vector::new(1.0, 1.0, 0.0));
+    let b = Quaternion::from_axis_angle(-1.1, &Vector::new(0.0, 1.0, 2.0));
+
+    for t in [0.0, 0.3, 0.5, 1.0] {
+        assert_near(a.nlerp(&b, t).magnitude(), 1.0);
+    }
+    // By symmetry both interpolations bisect the arc at t = 0.5.
+    assert_quaternion_close(&a.nlerp(&b, 0.5), &a.slerp(&b, 0.5));
+}
+
+#[test]
+fn slerp_of_nearly_identical_rotations_stays_finite() {
+    let a = Quaternion::from_axis_angle(1.0, &Vector::y_axis());
+    let b = Quaternion::from_axis_angle(1.0 + 1e-9, &Vector::y_axis());
+
+    let q = a.slerp(&b, 0.5);
+    assert_near(q.magnitude(), 1.0);
+    assert_quaternion_close(
+        &q,
+        &Quaternion::from_axis_angle(1.0 + 0.5e-9, &Vector::y_axis()),
+    );
+    assert_quaternion_close(&a.slerp(&a, 0.7), &a);
+}
+
+#[test]
+fn matrix_interpolation_goes_through_quaternions() {
+    let a = Matrix::rotation(0.4, &Vector::z_axis());
+    let b = Matrix::rotation(1.4, &Vector::z_axis());
+    let expected = Matrix::rotation(0.9, &Vector::z_axis());
+
+    assert_matrix_close(&a.slerp(&b, 0.5), &expected);
+    assert_matrix_close(&a.nlerp(&b, 0.5), &expected);
+    assert_matrix_close(&a.slerp(&b, 0.0), &a);
+    assert_matrix_close(&a.slerp(&b, 1.0), &b);
+}
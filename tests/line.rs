@@ -0,0 +1,111 @@
+use threed::{Line, Segment, Triangle, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+fn segment(start: [f64; 3], end: [f64; 3]) -> Segment<f64> {
+    Segment::new(
+        Vector::new(start[0], start[1], start[2]),
+        Vector::new(end[0], end[1], end[2]),
+    )
+}
+
+#[test]
+fn skew_segments() {
+    let a = segment([-1.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+    let b = segment([0.0, -1.0, 2.0], [0.0, 1.0, 2.0]);
+    let (p, q) = a.closest_points(&b);
+    assert_close(&p, &Vector::new(0.0, 0.0, 0.0));
+    assert_close(&q, &Vector::new(0.0, 0.0, 2.0));
+    assert_near(a.distance_to_segment(&b), 2.0);
+
+    // The closest points of the lines lie outside, so both get clamped.
+    let c = segment([3.0, 1.0, 1.0], [3.0, 4.0, 1.0]);
+    let (p, q) = a.closest_points(&c);
+    assert_close(&p, &Vector::new(1.0, 0.0, 0.0));
+    assert_close(&q, &Vector::new(3.0, 1.0, 1.0));
+}
+
+#[test]
+fn parallel_segments() {
+    let a = segment([0.0, 0.0, 0.0], [2.0, 0.0, 0.0]);
+
+    // Overlapping, any pair across the overlap is closest.
+    let b = segment([3.0, 1.0, 0.0], [1.0, 1.0, 0.0]);
+    let (p, q) = a.closest_points(&b);
+    assert_near((&q - &p).magnitude(), 1.0);
+    assert_near(a.distance(&p), 0.0);
+    assert_near(b.distance(&q), 0.0);
+    assert_near(a.distance_to_segment(&b), 1.0);
+
+    // Disjoint, the facing endpoints are closest.
+    let c = segment([3.0, 1.0, 0.0], [5.0, 1.0, 0.0]);
+    let (p, q) = a.closest_points(&c);
+    assert_close(&p, &Vector::new(2.0, 0.0, 0.0));
+    assert_close(&q, &Vector::new(3.0, 1.0, 0.0));
+}
+
+#[test]
+fn zero_length_segments_act_as_points() {
+    let a = segment([0.0, 0.0, 0.0], [2.0, 0.0, 0.0]);
+    let point = segment([1.0, 3.0, 0.0], [1.0, 3.0, 0.0]);
+
+    let (p, q) = a.closest_points(&point);
+    assert_close(&p, &Vector::new(1.0, 0.0, 0.0));
+    assert_close(&q, &point.start);
+    let (p, q) = point.closest_points(&a);
+    assert_close(&p, &point.start);
+    assert_close(&q, &Vector::new(1.0, 0.0, 0.0));
+
+    let other = segment([4.0, 3.0, 0.0], [4.0, 3.0, 0.0]);
+    let (p, q) = point.closest_points(&other);
+    assert_close(&p, &point.start);
+    assert_close(&q, &other.start);
+    assert_near(point.distance_to_segment(&other), 3.0);
+}
+
+#[test]
+fn segment_point_queries() {
+    let a = segment([0.0, 0.0, 0.0], [2.0, 0.0, 0.0]);
+    assert_near(a.length(), 2.0);
+    assert_close(&a.at(0.25), &Vector::new(0.5, 0.0, 0.0));
+    assert_close(
+        &a.closest_point(&Vector::new(-1.0, 1.0, 0.0)),
+        &Vector::default(),
+    );
+    assert_near(a.distance(&Vector::new(1.0, 0.0, 2.0)), 2.0);
+}
+
+#[test]
+fn lines_connect_along_the_common_perpendicular() {
+    let a = Line::new(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+    let b = Line::new(Vector::new(5.0, 0.0, 2.0), Vector::new(0.0, 2.0, 0.0));
+    let connector = a.shortest_connector(&b);
+    assert_close(&connector.start, &Vector::new(5.0, 0.0, 0.0));
+    assert_close(&connector.end, &Vector::new(5.0, 0.0, 2.0));
+
+    let parallel = Line::new(Vector::new(3.0, 1.0, 0.0), Vector::new(-2.0, 0.0, 0.0));
+    let connector = a.shortest_connector(&parallel);
+    assert_close(&connector.start, &a.point);
+    assert_close(&connector.end, &Vector::new(0.0, 1.0, 0.0));
+    assert_near(a.distance(&Vector::new(7.0, 0.0, -3.0)), 3.0);
+}
+
+#[test]
+fn segment_against_triangle() {
+    let triangle = Triangle::new(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(4.0, 0.0, 0.0),
+        Vector::new(0.0, 4.0, 0.0),
+    );
+
+    let piercing = segment([1.0, 1.0, -1.0], [1.0, 1.0, 1.0]);
+    let (p, q) = piercing.closest_points_to_triangle(&triangle);
+    assert_close(&p, &Vector::new(1.0, 1.0, 0.0));
+    assert_close(&q, &Vector::new(1.0, 1.0, 0.0));
+
+    let above = segment([1.0, 1.0, 1.0], [5.0, 5.0, 3.0]);
+    let (p, q) = above.closest_points_to_triangle(&triangle);
+    assert_close(&p, &Vector::new(1.0, 1.0, 1.0));
+    assert_close(&q, &Vector::new(1.0, 1.0, 0.0));
+}
@@ -0,0 +1,97 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{Matrix, Matrix4, Vector};
+
+mod common;
+use common::{assert_close, assert_matrix4_close, assert_matrix_close, assert_near};
+
+fn affine() -> Matrix4<f64> {
+    Matrix4::translation(&Vector::new(1.0, -2.0, 3.0))
+        * Matrix4::rotation(0.7, &Vector::new(1.0, 2.0, 2.0).normalize())
+        * Matrix4::scaling(&Vector::new(2.0, 0.5, 3.0))
+}
+
+#[test]
+fn builders_act_on_points_and_vectors() {
+    let p = Vector::new(1.0, 2.0, 3.0);
+
+    let translation = Matrix4::translation(&Vector::new(1.0, -2.0, 0.5));
+    assert_close(
+        &translation.transform_point(&p).unwrap(),
+        &Vector::new(2.0, 0.0, 3.5),
+    );
+    assert_close(&translation.transform_vector(&p), &p);
+
+    let scaling = Matrix4::scaling(&Vector::new(2.0, 3.0, -1.0));
+    assert_close(
+        &scaling.transform_point(&p).unwrap(),
+        &Vector::new(2.0, 6.0, -3.0),
+    );
+
+    let rotation = Matrix4::rotation(FRAC_PI_2, &Vector::z_axis());
+    assert_close(
+        &rotation.transform_point(&Vector::x_axis()).unwrap(),
+        &Vector::y_axis(),
+    );
+    assert_matrix4_close(
+        &Matrix4::rotation_degree(90.0, &Vector::z_axis()),
+        &rotation,
+    );
+}
+
+#[test]
+fn products_apply_right_to_left() {
+    let p = Vector::new(0.5, -1.0, 2.0);
+
+    // Scale first, then rotate, then translate.
+    let scaled = Vector::new(1.0, -0.5, 6.0);
+    let rotated = Matrix::rotation(0.7, &Vector::new(1.0, 2.0, 2.0).normalize()) * &scaled;
+    let expected = rotated + Vector::new(1.0, -2.0, 3.0);
+    assert_close(&affine().transform_point(&p).unwrap(), &expected);
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let m = affine();
+    let inverse = m.inverse().unwrap();
+
+    assert_matrix4_close(&(&m * &inverse), &Matrix4::unity());
+    assert_matrix4_close(&(&inverse * &m), &Matrix4::unity());
+    assert_near(m.determinant(), 3.0);
+    assert!(Matrix4::scaling(&Vector::new(1.0, 0.0, 1.0))
+        .inverse()
+        .is_none());
+}
+
+#[test]
+fn matrix_embeds_as_the_linear_part() {
+    let linear = Matrix::rotation(0.3, &Vector::y_axis()) * 2.0;
+    let embedded = Matrix4::from(&linear);
+
+    assert_matrix_close(&Matrix::from(&embedded), &linear);
+    assert_eq!(
+        (embedded.m14, embedded.m24, embedded.m34, embedded.m44),
+        (0.0, 0.0, 0.0, 1.0)
+    );
+    assert_eq!((embedded.m41, embedded.m42, embedded.m43), (0.0, 0.0, 0.0));
+    let v = Vector::new(1.0, 2.0, 3.0);
+    assert_close(&embedded.transform_vector(&v), &(&linear * &v));
+}
+
+#[test]
+fn projective_points_are_divided_by_w() {
+    // Maps (x, y, z) to (x, y, z, z), a pinhole camera looking down +z.
+    let mut m = Matrix4::unity();
+    m.m43 = 1.0;
+    m.m44 = 0.0;
+
+    assert_close(
+        &m.transform_point(&Vector::new(2.0, 4.0, 2.0)).unwrap(),
+        &Vector::new(1.0, 2.0, 1.0),
+    );
+    assert!(m.transform_point(&Vector::new(1.0, 1.0, 0.0)).is_none());
+    assert_close(
+        &m.transform_vector(&Vector::new(1.0, 1.0, 0.0)),
+        &Vector::new(1.0, 1.0, 0.0),
+    );
+}
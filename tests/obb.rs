@@ -0,0 +1,90 @@
+use std::f64::consts::{FRAC_PI_4, SQRT_2};
+
+use threed::{Aabb, Matrix, Obb, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+fn unit_cube(center: Vector<f64>, orientation: Matrix<f64>) -> Obb<f64> {
+    Obb::new(center, Vector::scalar(1.0), orientation)
+}
+
+// Overlap of the projections of both boxes onto `axis`.
+fn overlap_along(a: &Obb<f64>, b: &Obb<f64>, axis: &Vector<f64>) -> bool {
+    let project = |obb: &Obb<f64>| {
+        let values: Vec<_> = obb.corners().iter().map(|c| c.dot(axis)).collect();
+        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        (min, max)
+    };
+    let (a, b) = (project(a), project(b));
+    a.0 <= b.1 && b.0 <= a.1
+}
+
+#[test]
+fn edge_cross_axis_alone_separates() {
+    // One box stands on an edge along y, the other hangs from an edge along
+    // x above it. Only the z axis, the cross product of both edges, tells
+    // them apart.
+    let a = unit_cube(
+        Vector::default(),
+        Matrix::rotation(FRAC_PI_4, &Vector::y_axis()),
+    );
+    let b = unit_cube(
+        Vector::new(0.0, 0.0, 2.0 * SQRT_2 + 0.05),
+        Matrix::rotation(FRAC_PI_4, &Vector::x_axis()),
+    );
+    for i in 0..3 {
+        assert!(overlap_along(&a, &b, &a.axis(i)));
+        assert!(overlap_along(&a, &b, &b.axis(i)));
+    }
+    assert!(!overlap_along(&a, &b, &Vector::z_axis()));
+
+    assert!(!a.intersects(&b));
+    assert!(!b.intersects(&a));
+
+    let overlapping = unit_cube(
+        Vector::new(0.0, 0.0, 2.0 * SQRT_2 - 0.05),
+        b.orientation.clone(),
+    );
+    assert!(a.intersects(&overlapping));
+}
+
+#[test]
+fn face_axes_separate_boxes() {
+    let rotation = Matrix::rotation(0.7, &Vector::new(1.0, 2.0, 2.0).normalize());
+    let a = unit_cube(Vector::default(), Matrix::unity());
+
+    assert!(a.intersects(&unit_cube(Vector::new(1.5, 0.5, 0.0), rotation.clone())));
+    assert!(!a.intersects(&unit_cube(Vector::new(4.0, 0.0, 0.0), rotation.clone())));
+    assert!(a.intersects_aabb(&Aabb::new(
+        Vector::new(0.5, 0.5, 0.5),
+        Vector::new(2.0, 2.0, 2.0)
+    )));
+    assert!(!a.intersects_aabb(&Aabb::new(
+        Vector::new(1.5, -1.0, -1.0),
+        Vector::new(2.0, 1.0, 1.0)
+    )));
+}
+
+#[test]
+fn point_queries_use_the_local_axes() {
+    let obb = Obb::new(
+        Vector::new(1.0, 0.0, 0.0),
+        Vector::new(2.0, 1.0, 0.5),
+        Matrix::rotation(FRAC_PI_4, &Vector::z_axis()),
+    );
+    let diagonal = Vector::new(1.0, 1.0, 0.0).normalize();
+
+    assert!(obb.contains(&(Vector::new(1.0, 0.0, 0.0) + &diagonal * 1.9)));
+    assert!(!obb.contains(&(Vector::new(1.0, 0.0, 0.0) + &diagonal * 2.1)));
+    let outside = Vector::new(1.0, 0.0, 0.0) + &diagonal * 3.0 + Vector::new(0.0, 0.0, 1.5);
+    assert_close(
+        &obb.closest_point(&outside),
+        &(Vector::new(1.0, 0.0, 0.5) + &diagonal * 2.0),
+    );
+    assert_near(obb.distance_squared(&outside), 2.0);
+
+    let aabb = obb.aabb().expand(1e-12);
+    assert!(obb.corners().iter().all(|corner| aabb.contains(corner)));
+}
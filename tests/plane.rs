@@ -0,0 +1,100 @@
+use threed::{Plane, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+fn tilted() -> Plane<f64> {
+    Plane::from_point_normal(
+        &Vector::new(1.0, 2.0, 0.0),
+        Vector::new(1.0, 1.0, 1.0).normalize(),
+    )
+}
+
+#[test]
+fn three_planes_meet_in_a_point() {
+    let a = Plane::new(Vector::x_axis(), 1.0);
+    let b = Plane::new(Vector::new(0.0, 2.0, 0.0), 4.0);
+    let point = Plane::intersect_planes(&a, &b, &tilted()).unwrap();
+
+    assert_close(&point, &Vector::new(1.0, 2.0, 0.0));
+    for plane in [&a, &b, &tilted()] {
+        assert_near(plane.signed_distance(&point), 0.0);
+    }
+}
+
+#[test]
+fn parallel_planes_do_not_meet_in_a_point() {
+    let a = Plane::new(Vector::z_axis(), 0.0);
+    let b = Plane::new(Vector::new(0.0, 0.0, -2.0), 3.0);
+    let c = Plane::new(Vector::x_axis(), 1.0);
+    assert_eq!(Plane::intersect_planes(&a, &b, &c), None);
+
+    // All three parallel.
+    let d = Plane::new(Vector::z_axis(), 5.0);
+    assert_eq!(Plane::intersect_planes(&a, &b, &d), None);
+
+    // No two of them parallel, but the normals are coplanar like those of
+    // the side faces of a prism.
+    let e = Plane::new(Vector::new(1.0, 1.0, 0.0), 1.0);
+    let f = Plane::new(Vector::new(1.0, -1.0, 0.0), 1.0);
+    assert_eq!(Plane::intersect_planes(&c, &e, &f), None);
+}
+
+#[test]
+fn from_points_faces_counter_clockwise_side() {
+    let plane = Plane::from_points(
+        &Vector::new(0.0, 0.0, 2.0),
+        &Vector::new(1.0, 0.0, 2.0),
+        &Vector::new(0.0, 1.0, 2.0),
+    )
+    .unwrap();
+    assert_close(&plane.normal, &Vector::z_axis());
+    assert_near(plane.offset, 2.0);
+    assert_near(plane.signed_distance(&Vector::new(3.0, -1.0, 5.0)), 3.0);
+
+    let collinear = Plane::from_points(
+        &Vector::new(0.0, 0.0, 0.0),
+        &Vector::new(1.0, 1.0, 1.0),
+        &Vector::new(2.0, 2.0, 2.0),
+    );
+    assert_eq!(collinear, None);
+}
+
+#[test]
+fn projection_and_reflection() {
+    let plane = tilted();
+    let p = Vector::new(4.0, -1.0, 2.5);
+
+    let projected = plane.project_point(&p);
+    assert_near(plane.signed_distance(&projected), 0.0);
+    let reflected = plane.reflect_point(&p);
+    assert_near(
+        plane.signed_distance(&reflected),
+        -plane.signed_distance(&p),
+    );
+    assert_close(&((&p + &reflected) / 2.0), &projected);
+    assert_close(&plane.reflection().transform_point(&p).unwrap(), &reflected);
+
+    let direction = Vector::new(1.0, -2.0, 0.5);
+    let expected = direction.clone() - &plane.normal * (2.0 * direction.dot(&plane.normal));
+    assert_close(&(plane.reflection_matrix() * &direction), &expected);
+
+    let scaled = Plane::new(&plane.normal * 3.0, plane.offset * 3.0);
+    assert_close(&scaled.normalize().normal, &plane.normal);
+    assert_near(scaled.signed_distance(&p), plane.signed_distance(&p));
+}
+
+#[test]
+fn line_intersection() {
+    let plane = tilted();
+    let point = plane
+        .intersect_line(&Vector::default(), &Vector::new(1.0, 0.0, 0.0))
+        .unwrap();
+    assert_near(plane.signed_distance(&point), 0.0);
+    assert_close(&point, &Vector::new(3.0, 0.0, 0.0));
+
+    assert_eq!(
+        plane.intersect_line(&Vector::default(), &Vector::new(1.0, -1.0, 0.0)),
+        None
+    );
+}
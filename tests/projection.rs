@@ -0,0 +1,148 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{DepthRange, Handedness, Matrix4, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+const DEPTHS: [DepthRange; 2] = [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne];
+const HANDEDNESS: [Handedness; 2] = [Handedness::Right, Handedness::Left];
+
+fn ndc_bounds(depth: DepthRange) -> (f64, f64) {
+    match depth {
+        DepthRange::NegativeOneToOne => (-1.0, 1.0),
+        DepthRange::ZeroToOne => (0.0, 1.0),
+    }
+}
+
+// Point at `distance` along the view direction, offset by `x`, `y`.
+fn ahead(x: f64, y: f64, distance: f64, handedness: Handedness) -> Vector<f64> {
+    match handedness {
+        Handedness::Right => Vector::new(x, y, -distance),
+        Handedness::Left => Vector::new(x, y, distance),
+    }
+}
+
+fn ndc_z(m: &Matrix4<f64>, point: &Vector<f64>) -> f64 {
+    m.transform_point(point).unwrap().z
+}
+
+#[test]
+fn perspective_maps_near_and_far_to_the_depth_range() {
+    for depth in DEPTHS {
+        for handedness in HANDEDNESS {
+            let (low, high) = ndc_bounds(depth);
+            let m = Matrix4::perspective(FRAC_PI_2, 2.0, 0.5, 100.0, depth, handedness);
+            assert_near(ndc_z(&m, &ahead(0.0, 0.0, 0.5, handedness)), low);
+            assert_near(ndc_z(&m, &ahead(0.0, 0.0, 100.0, handedness)), high);
+
+            // A 90 degree field of view reaches y = d at distance d, and the
+            // aspect ratio doubles the horizontal extent.
+            let corner = m.transform_point(&ahead(6.0, 3.0, 3.0, handedness));
+            let corner = corner.unwrap();
+            assert_near(corner.x, 1.0);
+            assert_near(corner.y, 1.0);
+
+            let reversed =
+                Matrix4::perspective_reversed_z(FRAC_PI_2, 2.0, 0.5, 100.0, depth, handedness);
+            assert_near(ndc_z(&reversed, &ahead(0.0, 0.0, 0.5, handedness)), high);
+            assert_near(ndc_z(&reversed, &ahead(0.0, 0.0, 100.0, handedness)), low);
+        }
+    }
+}
+
+#[test]
+fn infinite_perspective_approaches_the_far_end() {
+    for depth in DEPTHS {
+        for handedness in HANDEDNESS {
+            let (low, high) = ndc_bounds(depth);
+            let m = Matrix4::perspective_infinite(1.0, 1.5, 0.1, depth, handedness);
+            assert_near(ndc_z(&m, &ahead(0.0, 0.0, 0.1, handedness)), low);
+            let far = ndc_z(&m, &ahead(0.0, 0.0, 1e9, handedness));
+            assert!(far < high && high - far < 1e-9, "far depth {}", far);
+
+            let reversed =
+                Matrix4::perspective_infinite_reversed_z(1.0, 1.5, 0.1, depth, handedness);
+            assert_near(ndc_z(&reversed, &ahead(0.0, 0.0, 0.1, handedness)), high);
+            let far = ndc_z(&reversed, &ahead(0.0, 0.0, 1e9, handedness));
+            assert!(far > low && far - low < 1e-9, "far depth {}", far);
+        }
+    }
+}
+
+#[test]
+fn orthographic_maps_the_box_to_the_unit_cube() {
+    for depth in DEPTHS {
+        for handedness in HANDEDNESS {
+            let (low, high) = ndc_bounds(depth);
+            let m = Matrix4::orthographic(-2.0, 4.0, -1.0, 3.0, 1.0, 11.0, depth, handedness);
+            assert_close(
+                &m.transform_point(&ahead(-2.0, -1.0, 1.0, handedness))
+                    .unwrap(),
+                &Vector::new(-1.0, -1.0, low),
+            );
+            assert_close(
+                &m.transform_point(&ahead(4.0, 3.0, 11.0, handedness))
+                    .unwrap(),
+                &Vector::new(1.0, 1.0, high),
+            );
+
+            let reversed = Matrix4::orthographic_reversed_z(
+                -2.0, 4.0, -1.0, 3.0, 1.0, 11.0, depth, handedness,
+            );
+            assert_near(ndc_z(&reversed, &ahead(0.0, 0.0, 1.0, handedness)), high);
+            assert_near(ndc_z(&reversed, &ahead(0.0, 0.0, 11.0, handedness)), low);
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "near and far plane coincide")]
+fn perspective_rejects_coinciding_planes() {
+    Matrix4::perspective(
+        1.0,
+        1.0,
+        2.0,
+        2.0,
+        DepthRange::default(),
+        Handedness::default(),
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "near plane must be in front of the eye")]
+fn perspective_rejects_non_positive_near() {
+    Matrix4::perspective_infinite(1.0, 1.0, 0.0, DepthRange::default(), Handedness::default());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "field of view out of (0, pi)")]
+fn perspective_rejects_degenerate_field_of_view() {
+    Matrix4::perspective(
+        std::f64::consts::PI,
+        1.0,
+        0.1,
+        10.0,
+        DepthRange::default(),
+        Handedness::default(),
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "empty orthographic volume")]
+fn orthographic_rejects_empty_volume() {
+    Matrix4::orthographic(
+        1.0,
+        1.0,
+        0.0,
+        1.0,
+        0.0,
+        1.0,
+        DepthRange::default(),
+        Handedness::default(),
+    );
+}
@@ -0,0 +1,111 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use threed::{Matrix, Quaternion, Vector};
+
+mod common;
+use common::{assert_close, assert_matrix_close, assert_near, assert_quaternion_close};
+
+#[test]
+fn hamilton_product_follows_the_unit_rules() {
+    let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+    let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+    let minus_one = Quaternion::new(-1.0, 0.0, 0.0, 0.0);
+
+    assert_quaternion_close(&(&i * &j), &k);
+    assert_quaternion_close(&(&j * &i), &-&k);
+    assert_quaternion_close(&(&j * &k), &i);
+    assert_quaternion_close(&(&k * &i), &j);
+    assert_quaternion_close(&(&i * &i), &minus_one);
+    assert_quaternion_close(&(&i * &j * &k), &minus_one);
+}
+
+#[test]
+fn inverse_undoes_the_product() {
+    let q = Quaternion::new(1.0, -2.0, 0.5, 3.0);
+
+    let inverse = q.inverse().unwrap();
+    assert_quaternion_close(&(&q * &inverse), &Quaternion::identity());
+    assert_quaternion_close(&(&inverse * &q), &Quaternion::identity());
+    assert_quaternion_close(&q.conjugate(), &Quaternion::new(1.0, 2.0, -0.5, -3.0));
+    assert_near(q.magnitude_squared(), 14.25);
+    assert!(Quaternion::<f64>::default().inverse().is_none());
+}
+
+#[test]
+fn axis_angle_round_trips() {
+    let axis = Vector::new(1.0, -2.0, 2.0);
+    let q = Quaternion::from_axis_angle(2.0, &axis);
+
+    assert_near(q.magnitude(), 1.0);
+    let (angle, unit) = q.to_axis_angle();
+    assert_near(angle, 2.0);
+    assert_close(&unit, &(axis / 3.0));
+
+    let degree = Quaternion::from_axis_angle_degree(90.0, &Vector::z_axis());
+    assert_quaternion_close(
+        &degree,
+        &Quaternion::from_axis_angle(FRAC_PI_2, &Vector::z_axis()),
+    );
+    assert_near(Quaternion::<f64>::identity().to_axis_angle().0, 0.0);
+}
+
+#[test]
+fn rotation_agrees_with_matrix_conversion() {
+    let axis = Vector::new(0.3, 1.0, -0.4).normalize();
+    let q = Quaternion::from_axis_angle(1.2, &axis);
+    let v = Vector::new(2.0, -1.0, 0.5);
+
+    assert_close(&q.rotate(&v), &(Matrix::from(&q) * &v));
+    assert_close(&(&q * &v), &(Matrix::rotation(1.2, &axis) * &v));
+    assert_close(
+        &Quaternion::from_axis_angle(FRAC_PI_2, &Vector::z_axis()).rotate(&Vector::x_axis()),
+        &Vector::y_axis(),
+    );
+}
+
+#[test]
+fn products_compose_right_to_left() {
+    let first = Quaternion::from_axis_angle(FRAC_PI_2, &Vector::z_axis());
+    let second = Quaternion::from_axis_angle(FRAC_PI_2, &Vector::x_axis());
+    let v = Vector::new(1.0, 2.0, 3.0);
+
+    let composed = &second * &first;
+    assert_close(&composed.rotate(&v), &second.rotate(&first.rotate(&v)));
+    assert_matrix_close(
+        &Matrix::from(&composed),
+        &(Matrix::from(&second) * Matrix::from(&first)),
+    );
+}
+
+#[test]
+fn matrix_conversion_round_trips_in_every_branch() {
+    // Small angles take the trace branch, half turns the diagonal branches.
+    let cases = [
+        (0.4, Vector::new(1.0, 2.0, 3.0)),
+        (PI, Vector::x_axis()),
+        (PI, Vector::y_axis()),
+        (PI, Vector::z_axis()),
+        (3.0, Vector::new(1.0, -1.0, 0.5)),
+    ];
+    for (angle, axis) in cases {
+        let q = Quaternion::from_axis_angle(angle, &axis);
+        let back = Quaternion::from(Matrix::from(&q));
+        // q and -q are the same rotation.
+        let back = if back.dot(&q) < 0.0 { -back } else { back };
+        assert_quaternion_close(&back, &q);
+    }
+}
+
+#[test]
+fn component_wise_operators() {
+    let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let b = Quaternion::new(0.5, -1.0, 0.0, 2.0);
+
+    assert_quaternion_close(&(&a + &b), &Quaternion::new(1.5, 1.0, 3.0, 6.0));
+    assert_quaternion_close(&(&a - &b), &Quaternion::new(0.5, 3.0, 3.0, 2.0));
+    assert_quaternion_close(&(&a * 2.0), &Quaternion::new(2.0, 4.0, 6.0, 8.0));
+    assert_quaternion_close(&(&a / 2.0), &Quaternion::new(0.5, 1.0, 1.5, 2.0));
+    assert_near(a.dot(&b), 6.5);
+    assert_close(&a.vector(), &Vector::new(2.0, 3.0, 4.0));
+}
@@ -0,0 +1,158 @@
+use threed::{Ray, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+#[test]
+fn plane_hits_in_front_and_misses_behind_or_parallel() {
+    let normal = Vector::new(0.0, 0.0, 2.0);
+
+    let hit = Ray::new(Vector::new(1.0, 2.0, 5.0), Vector::new(0.0, 0.0, -1.0))
+        .intersect_plane(&normal, 2.0)
+        .unwrap();
+    assert_near(hit.distance, 4.0);
+    assert_close(&hit.point, &Vector::new(1.0, 2.0, 1.0));
+    assert_close(&hit.normal, &Vector::z_axis());
+
+    assert!(
+        Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0))
+            .intersect_plane(&normal, 2.0)
+            .is_none()
+    );
+    assert!(
+        Ray::new(Vector::new(0.0, 0.0, 5.0), Vector::new(1.0, 0.0, 0.0))
+            .intersect_plane(&normal, 2.0)
+            .is_none()
+    );
+}
+
+#[test]
+fn sphere_reports_nearest_hit_and_exit_from_inside() {
+    let center = Vector::new(0.0, 0.0, -10.0);
+
+    let hit = Ray::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, -1.0))
+        .intersect_sphere(&center, 2.0)
+        .unwrap();
+    assert_near(hit.distance, 8.0);
+    assert_close(&hit.point, &Vector::new(0.0, 0.0, -8.0));
+    assert_close(&hit.normal, &Vector::z_axis());
+
+    let inside = Ray::new(Vector::new(0.0, 0.0, -10.0), Vector::new(1.0, 0.0, 0.0))
+        .intersect_sphere(&center, 2.0)
+        .unwrap();
+    assert_near(inside.distance, 2.0);
+    assert_close(&inside.normal, &Vector::x_axis());
+
+    assert!(
+        Ray::new(Vector::new(0.0, 3.0, 0.0), Vector::new(0.0, 0.0, -1.0))
+            .intersect_sphere(&center, 2.0)
+            .is_none()
+    );
+    assert!(
+        Ray::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0))
+            .intersect_sphere(&center, 2.0)
+            .is_none()
+    );
+}
+
+#[test]
+fn sphere_without_positive_radius_is_missed() {
+    let ray = Ray::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, -1.0));
+    let center = Vector::new(0.0, 0.0, -10.0);
+
+    assert!(ray.intersect_sphere(&center, 0.0).is_none());
+    assert!(ray.intersect_sphere(&center, -2.0).is_none());
+}
+
+#[test]
+fn aabb_slabs_report_the_entered_face() {
+    let min = Vector::new(-1.0, -1.0, -1.0);
+    let max = Vector::new(1.0, 1.0, 1.0);
+
+    let hit = Ray::new(Vector::new(-5.0, 0.5, 0.0), Vector::new(1.0, 0.0, 0.0))
+        .intersect_aabb(&min, &max)
+        .unwrap();
+    assert_near(hit.distance, 4.0);
+    assert_close(&hit.point, &Vector::new(-1.0, 0.5, 0.0));
+    assert_close(&hit.normal, &-Vector::x_axis());
+
+    let diagonal = Ray::new(Vector::new(3.0, 3.0, 0.5), Vector::new(-1.0, -2.0, 0.0))
+        .intersect_aabb(&min, &max)
+        .unwrap();
+    assert_close(&diagonal.point, &Vector::new(1.0, -1.0, 0.5));
+    assert_close(&diagonal.normal, &Vector::x_axis());
+
+    let inside = Ray::new(Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 2.0))
+        .intersect_aabb(&min, &max)
+        .unwrap();
+    assert_near(inside.distance, 0.5);
+    assert_close(&inside.normal, &Vector::z_axis());
+
+    assert!(
+        Ray::new(Vector::new(-5.0, 2.0, 0.0), Vector::new(1.0, 0.0, 0.0))
+            .intersect_aabb(&min, &max)
+            .is_none()
+    );
+    assert!(
+        Ray::new(Vector::new(5.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0))
+            .intersect_aabb(&min, &max)
+            .is_none()
+    );
+    assert!(
+        Ray::new(Vector::new(-5.0, 0.0, 0.0), Vector::new(1.0, 5.0, 0.0))
+            .intersect_aabb(&min, &max)
+            .is_none()
+    );
+}
+
+#[test]
+fn triangle_reports_barycentrics_and_respects_backface_culling() {
+    let a = Vector::new(0.0, 0.0, 0.0);
+    let b = Vector::new(2.0, 0.0, 0.0);
+    let c = Vector::new(0.0, 2.0, 0.0);
+
+    let front = Ray::new(Vector::new(0.5, 0.5, 3.0), Vector::new(0.0, 0.0, -1.0));
+    let hit = front.intersect_triangle(&a, &b, &c, true).unwrap();
+    assert_near(hit.hit.distance, 3.0);
+    assert_close(&hit.hit.point, &Vector::new(0.5, 0.5, 0.0));
+    assert_close(&hit.hit.normal, &Vector::z_axis());
+    assert_close(&hit.barycentric, &Vector::new(0.5, 0.25, 0.25));
+    let weighted = &a * hit.barycentric.x + &b * hit.barycentric.y + &c * hit.barycentric.z;
+    assert_close(&weighted, &hit.hit.point);
+
+    let back = Ray::new(Vector::new(0.5, 0.5, -3.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(back.intersect_triangle(&a, &b, &c, true).is_none());
+    let hit = back.intersect_triangle(&a, &b, &c, false).unwrap();
+    assert_near(hit.hit.distance, 3.0);
+    assert_close(&hit.hit.normal, &Vector::z_axis());
+
+    assert!(
+        Ray::new(Vector::new(1.5, 1.5, 3.0), Vector::new(0.0, 0.0, -1.0))
+            .intersect_triangle(&a, &b, &c, false)
+            .is_none()
+    );
+    assert!(
+        Ray::new(Vector::new(0.5, 0.5, 3.0), Vector::new(1.0, 0.0, 0.0))
+            .intersect_triangle(&a, &b, &c, false)
+            .is_none()
+    );
+}
+
+#[test]
+fn disc_is_bounded_by_its_radius() {
+    let center = Vector::new(1.0, 1.0, 1.0);
+    let normal = Vector::new(0.0, 1.0, 0.0);
+
+    let hit = Ray::new(Vector::new(1.5, 5.0, 1.0), Vector::new(0.0, -1.0, 0.0))
+        .intersect_disc(&center, &normal, 1.0)
+        .unwrap();
+    assert_near(hit.distance, 4.0);
+    assert_close(&hit.point, &Vector::new(1.5, 1.0, 1.0));
+    assert_close(&hit.normal, &Vector::y_axis());
+
+    assert!(
+        Ray::new(Vector::new(2.5, 5.0, 1.0), Vector::new(0.0, -1.0, 0.0))
+            .intersect_disc(&center, &normal, 1.0)
+            .is_none()
+    );
+}
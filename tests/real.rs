@@ -0,0 +1,65 @@
+use std::f64::consts::PI;
+
+use threed::{Matrix, Quaternion, Real, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+// Generic over the scalar so both implementations run the same code.
+fn unit_circle<T: Real>(radians: T) -> T {
+    let (s, c) = (radians.sin(), radians.cos());
+    (s * s + c * c).sqrt()
+}
+
+#[test]
+fn real_is_implemented_for_f32_and_f64() {
+    assert!((unit_circle(0.7_f32) - 1.0).abs() <= 2.0 * f32::EPSILON);
+    assert_near(unit_circle(0.7_f64), 1.0);
+
+    assert_eq!(<f32 as Real>::pi(), std::f32::consts::PI);
+    assert_eq!(<f64 as Real>::epsilon(), f64::EPSILON);
+    assert_near(<f64 as Real>::from_f64(0.25), 0.25);
+    assert_near(Real::to_radians(180.0_f64), PI);
+    assert_near(Real::atan2(1.0_f64, -1.0), 0.75 * PI);
+    assert_near(Real::asin(1.0_f64), PI / 2.0);
+    assert_eq!(Real::clamp(3.0_f32, -1.0, 1.0), 1.0);
+    assert!(!Real::is_finite(f64::NAN));
+}
+
+#[test]
+fn f64_rotations_keep_double_precision() {
+    // A round trip through f32 would lose an angle this small entirely.
+    let angle: f64 = 1e-9;
+    let rotated = Matrix::rotation(angle, &Vector::z_axis()) * Vector::x_axis();
+    assert_eq!(rotated.y, angle.sin());
+    assert!(rotated.y != 0.0);
+
+    let q = Quaternion::from_axis_angle(angle, &Vector::z_axis());
+    let y = q.rotate(&Vector::x_axis()).y;
+    assert!((y - angle).abs() < 1e-24, "y {}", y);
+}
+
+#[test]
+fn degree_helpers_match_radians() {
+    let axis = Vector::new(2.0, -1.0, 2.0) / 3.0;
+    let v = Vector::new(1.0, 2.0, 3.0);
+
+    assert_close(
+        &v.rotate_degree_around(135.0, &axis),
+        &v.rotate_around(0.75 * PI, &axis),
+    );
+    assert_close(
+        &(Matrix::rotation_degree(135.0, &axis) * &v),
+        &(Matrix::rotation(0.75 * PI, &axis) * &v),
+    );
+}
+
+#[test]
+fn rotations_work_in_single_precision() {
+    let axis = Vector::<f32>::z_axis();
+    let rotated = Vector::<f32>::x_axis().rotate_degree_around(90.0, &axis);
+
+    assert!((&rotated - &Vector::y_axis()).magnitude() < 1e-6);
+    let q = Quaternion::from_axis_angle(std::f32::consts::FRAC_PI_2, &axis);
+    assert!((q.rotate(&Vector::x_axis()) - rotated).magnitude() < 1e-6);
+}
@@ -0,0 +1,76 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{Matrix, Quaternion, Vector};
+
+mod common;
+use common::assert_close;
+
+#[test]
+fn quarter_turns_around_the_principal_axes() {
+    let x = Vector::<f64>::x_axis();
+    let y = Vector::<f64>::y_axis();
+    let z = Vector::<f64>::z_axis();
+
+    assert_close(&(Matrix::rotation(FRAC_PI_2, &z) * &x), &y);
+    assert_close(&(Matrix::rotation(FRAC_PI_2, &x) * &y), &z);
+    assert_close(&(Matrix::rotation(FRAC_PI_2, &y) * &z), &x);
+    assert_close(&(Matrix::rotation(-FRAC_PI_2, &z) * &y), &x);
+}
+
+#[test]
+fn rotate_around_matches_known_values() {
+    let axis = Vector::new(1.0, 1.0, 1.0).normalize();
+    let v = Vector::new(1.0, 0.0, 0.0);
+
+    assert_close(
+        &v.rotate_degree_around(120.0, &axis),
+        &Vector::new(0.0, 1.0, 0.0),
+    );
+    assert_close(
+        &v.rotate_degree_around(240.0, &axis),
+        &Vector::new(0.0, 0.0, 1.0),
+    );
+    assert_close(
+        &Vector::new(1.0, 2.0, 3.0).rotate_degree_around(90.0, &Vector::z_axis()),
+        &Vector::new(-2.0, 1.0, 3.0),
+    );
+}
+
+#[test]
+fn matrix_vector_product_agrees_with_quaternion_rotation() {
+    let axis = Vector::new(0.3, -1.2, 2.0).normalize();
+    let v = Vector::new(-0.5, 4.0, 1.5);
+    let matrix = Matrix::rotation(0.9, &axis);
+    let quaternion = Quaternion::from_axis_angle(0.9, &axis);
+
+    assert_close(&(&matrix * &v), &quaternion.rotate(&v));
+}
+
+#[test]
+fn matrix_vector_product_reads_rows() {
+    let m = Matrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let v = Vector::new(1.0, 0.0, -1.0);
+
+    assert_close(&(&m * &v), &Vector::new(-2.0, -2.0, -2.0));
+    assert_close(&(&m * Vector::x_axis()), &Vector::new(1.0, 4.0, 7.0));
+}
+
+#[test]
+fn row_vector_product_reads_columns() {
+    let m = Matrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let v = Vector::new(1.0, 0.0, -1.0);
+
+    assert_close(&(&v * &m), &Vector::new(-6.0, -6.0, -6.0));
+    assert_close(&(&v * &m), &(m.transpose() * &v));
+}
+
+#[test]
+fn composed_rotations_apply_right_to_left() {
+    let x = Vector::<f64>::x_axis();
+    let about_z = Matrix::rotation(FRAC_PI_2, &Vector::z_axis());
+    let about_x = Matrix::rotation(FRAC_PI_2, &Vector::x_axis());
+
+    // x -> y under the z rotation, then y -> z under the x rotation.
+    assert_close(&((&about_x * &about_z) * &x), &Vector::z_axis());
+    assert_close(&(&about_x * (&about_z * &x)), &Vector::z_axis());
+}
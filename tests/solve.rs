@@ -0,0 +1,106 @@
+use threed::{InverseError, Matrix, Vector};
+
+mod common;
+use common::assert_matrix_close;
+
+// Needs row pivoting, its top left entry is zero.
+fn pivoting() -> Matrix<f64> {
+    Matrix::from_rows([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, -1.0, 2.0]])
+}
+
+// Rank two, the second row is twice the first.
+fn rank_deficient() -> Matrix<f64> {
+    Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]])
+}
+
+#[test]
+fn lu_factors_the_permuted_matrix() {
+    let a = pivoting();
+    let lu = a.lu();
+
+    assert_matrix_close(&(lu.p() * &a), &(lu.l() * lu.u()));
+    assert!((lu.determinant() - a.determinant()).abs() < 1e-12);
+    assert!(!lu.is_singular());
+    assert!(rank_deficient().lu().is_singular());
+}
+
+#[test]
+fn qr_has_orthogonal_q_and_triangular_r() {
+    let a = pivoting();
+    let qr = a.qr();
+
+    assert_matrix_close(&(&qr.q * &qr.r), &a);
+    assert_matrix_close(&(qr.q.transpose() * &qr.q), &Matrix::unity());
+    let r = qr.r.to_rows();
+    assert_eq!([r[1][0], r[2][0], r[2][1]], [0.0; 3]);
+}
+
+#[test]
+fn solve_satisfies_the_system() {
+    let a = pivoting();
+    let b = Vector::new(1.0, -2.0, 0.5);
+
+    let x = a.solve(&b).unwrap();
+    assert!((&a * &x - &b).magnitude() < 1e-12);
+    let x = a.qr().solve(&b).unwrap();
+    assert!((&a * &x - &b).magnitude() < 1e-12);
+    assert!(rank_deficient().solve(&b).is_none());
+}
+
+#[test]
+fn pseudo_inverse_reproduces_rank_deficient_matrix() {
+    let a = rank_deficient();
+    let pseudo = a.pseudo_inverse(1e-12);
+
+    assert_matrix_close(&(&a * &pseudo * &a), &a);
+    assert_matrix_close(&(&pseudo * &a * &pseudo), &pseudo);
+}
+
+#[test]
+fn condition_number_is_infinite_for_singular_matrices() {
+    let diagonal: Matrix<f64> = Matrix::new(4.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.5);
+    assert!((diagonal.condition_number() - 8.0).abs() < 1e-12);
+    assert_eq!(Matrix::<f64>::default().condition_number(), f64::INFINITY);
+    assert_eq!(rank_deficient().condition_number(), f64::INFINITY);
+}
+
+#[test]
+fn try_inverse_reports_each_failure() {
+    let a = pivoting();
+    let inverse = a.try_inverse(1e-8).unwrap();
+    assert_matrix_close(&(&a * &inverse), &Matrix::unity());
+
+    let mut rows = a.to_rows();
+    rows[1][2] = f64::NAN;
+    assert_eq!(
+        Matrix::from_rows(rows).try_inverse(1e-8),
+        Err(InverseError::NotFinite)
+    );
+
+    assert_eq!(
+        rank_deficient().try_inverse(1e-8),
+        Err(InverseError::Singular)
+    );
+
+    let nearly_flat: Matrix<f64> = Matrix::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1e-10);
+    match nearly_flat.try_inverse(1e-8) {
+        Err(InverseError::IllConditioned { condition }) => {
+            assert!((condition - 1e10).abs() < 1.0, "condition {}", condition)
+        }
+        other => panic!("expected IllConditioned, got {:?}", other),
+    }
+}
+
+#[test]
+fn inverse_only_rejects_exactly_singular_matrices() {
+    let nearly_flat: Matrix<f64> = Matrix::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1e-10);
+    let inverse = nearly_flat.inverse().unwrap();
+    assert!((inverse.m33 - 1e10).abs() < 1.0);
+    assert!(nearly_flat.try_inverse(1e-8).is_err());
+
+    assert!(Matrix::<f64>::default().inverse().is_none());
+    assert_matrix_close(
+        &(pivoting() * pivoting().inverse().unwrap()),
+        &Matrix::unity(),
+    );
+}
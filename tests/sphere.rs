@@ -0,0 +1,95 @@
+use threed::{Aabb, Plane, Sphere, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+// Ordered input that made the unshuffled Welzl algorithm quadratic.
+fn spiral(count: usize) -> Vec<Vector<f64>> {
+    (0..count)
+        .map(|i| {
+            let t = i as f64 * 0.01;
+            Vector::new(t.cos() * (1.0 + t), t.sin() * (1.0 + t), t * 0.1)
+        })
+        .collect()
+}
+
+fn assert_encloses(sphere: &Sphere<f64>, points: &[Vector<f64>]) {
+    for p in points {
+        let distance = (p - &sphere.center).magnitude();
+        assert!(distance <= sphere.radius * (1.0 + 1e-12), "{:?} outside", p);
+    }
+}
+
+#[test]
+fn welzl_finds_known_minimal_spheres() {
+    // Two antipodal points and some inside.
+    let points = [
+        Vector::new(-2.0, 1.0, 0.0),
+        Vector::new(0.5, 1.2, 0.3),
+        Vector::new(2.0, 1.0, 0.0),
+        Vector::new(0.0, 1.0, -1.0),
+    ];
+    let sphere = Sphere::welzl(&points).unwrap();
+    assert_close(&sphere.center, &Vector::new(0.0, 1.0, 0.0));
+    assert_near(sphere.radius, 2.0);
+
+    // A regular tetrahedron is bounded by its circumsphere.
+    let tetrahedron = [
+        Vector::new(1.0, 1.0, 1.0),
+        Vector::new(1.0, -1.0, -1.0),
+        Vector::new(-1.0, 1.0, -1.0),
+        Vector::new(-1.0, -1.0, 1.0),
+        Vector::new(0.2, -0.1, 0.3),
+    ];
+    let sphere = Sphere::welzl(&tetrahedron).unwrap();
+    assert_close(&sphere.center, &Vector::default());
+    assert_near(sphere.radius, 3.0_f64.sqrt());
+
+    // Points on a circle need only the circle.
+    let circle: Vec<_> = (0..12)
+        .map(|i| {
+            let angle = i as f64 * 0.5;
+            Vector::new(angle.cos(), angle.sin(), 0.0) * 3.0
+        })
+        .collect();
+    let sphere = Sphere::welzl(&circle).unwrap();
+    assert_close(&sphere.center, &Vector::default());
+    assert_near(sphere.radius, 3.0);
+
+    assert_eq!(Sphere::<f64>::welzl(&[]), None);
+    let single = Sphere::welzl(&[Vector::new(1.0, 2.0, 3.0)]).unwrap();
+    assert_eq!(single, Sphere::new(Vector::new(1.0, 2.0, 3.0), 0.0));
+}
+
+#[test]
+fn bounds_enclose_every_point() {
+    let points = spiral(3000);
+    let welzl = Sphere::welzl(&points).unwrap();
+    let ritter = Sphere::ritter(&points).unwrap();
+
+    assert_encloses(&welzl, &points);
+    assert_encloses(&ritter, &points);
+    assert!(welzl.radius <= ritter.radius);
+    assert_eq!(Sphere::<f64>::ritter(&[]), None);
+}
+
+#[test]
+fn sphere_overlap_tests() {
+    let sphere = Sphere::new(Vector::new(1.0, 0.0, 0.0), 1.0);
+
+    assert!(sphere.contains(&Vector::new(1.5, 0.5, 0.0)));
+    assert!(!sphere.contains(&Vector::new(2.5, 0.0, 0.0)));
+    assert!(sphere.intersects(&Sphere::new(Vector::new(3.0, 0.0, 0.0), 1.0)));
+    assert!(!sphere.intersects(&Sphere::new(Vector::new(3.1, 0.0, 0.0), 1.0)));
+
+    let aabb = Aabb::new(Vector::new(1.5, 0.5, -1.0), Vector::new(3.0, 1.0, 1.0));
+    assert!(sphere.intersects_aabb(&aabb));
+    let corner = Aabb::new(Vector::new(1.8, 0.8, -1.0), Vector::new(3.0, 1.0, 1.0));
+    assert!(!sphere.intersects_aabb(&corner));
+
+    // The normal needs not be of unit length.
+    assert!(sphere.intersects_plane(&Plane::new(Vector::new(0.0, 2.0, 0.0), 1.8)));
+    assert!(sphere.intersects_plane(&Plane::new(Vector::new(-3.0, 0.0, 0.0), 0.0)));
+    assert!(!sphere.intersects_plane(&Plane::new(Vector::new(0.0, 2.0, 0.0), 2.2)));
+    assert!(!sphere.intersects_plane(&Plane::new(Vector::new(0.0, 0.0, -1.0), 1.5)));
+}
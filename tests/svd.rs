@@ -0,0 +1,132 @@
+use threed::{Matrix, Vector};
+
+mod common;
+use common::{assert_matrix_close, assert_rotation};
+
+#[test]
+fn svd_reconstructs_with_rotations() {
+    let a = Matrix::from_rows([[3.0, 1.0, -2.0], [0.5, 2.0, 1.0], [-1.0, 0.0, 4.0]]);
+    let svd = a.svd();
+
+    assert_matrix_close(&(&svd.u * svd.sigma() * svd.v.transpose()), &a);
+    assert_rotation(&svd.u);
+    assert_rotation(&svd.v);
+    let sigma = &svd.singular_values;
+    assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z > 0.0);
+}
+
+#[test]
+fn reflection_makes_last_singular_value_negative() {
+    let axis = Vector::new(1.0, -2.0, 0.5).normalize();
+    let reflection = Matrix::new(2.0, 0.0, 0.0, 0.0, -0.5, 0.0, 0.0, 0.0, 1.0);
+    let a = Matrix::rotation(0.8, &axis) * reflection;
+    assert!(a.determinant() < 0.0);
+    let svd = a.svd();
+
+    assert_matrix_close(&(&svd.u * svd.sigma() * svd.v.transpose()), &a);
+    assert_rotation(&svd.u);
+    assert_rotation(&svd.v);
+    let sigma = &svd.singular_values;
+    assert!((sigma.x - 2.0).abs() < 1e-12);
+    assert!((sigma.y - 1.0).abs() < 1e-12);
+    assert!((sigma.z + 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn rank_deficient_svd_completes_u() {
+    // Outer product (1, 2, 3) (0.5, -1, 2)^T has rank one.
+    let a = Matrix::from_rows([[0.5, -1.0, 2.0], [1.0, -2.0, 4.0], [1.5, -3.0, 6.0]]);
+    let svd = a.svd();
+
+    assert_matrix_close(&(&svd.u * svd.sigma() * svd.v.transpose()), &a);
+    assert_rotation(&svd.u);
+    assert_rotation(&svd.v);
+    let expected = 14.0_f64.sqrt() * 5.25_f64.sqrt();
+    assert!((svd.singular_values.x - expected).abs() < 1e-12);
+    assert!(svd.singular_values.y.abs() < 1e-12);
+    assert!(svd.singular_values.z.abs() < 1e-12);
+
+    let zero = Matrix::<f64>::default().svd();
+    assert_eq!(zero.singular_values, Vector::default());
+    assert_rotation(&zero.u);
+}
+
+#[test]
+fn svd_works_in_single_precision() {
+    let a: Matrix<f32> = Matrix::from_rows([[3.0, 1.0, -2.0], [0.5, 2.0, 1.0], [-1.0, 0.0, 4.0]]);
+    let svd = a.svd();
+
+    let reconstructed = (&svd.u * svd.sigma() * svd.v.transpose()).to_rows();
+    for (r, e) in reconstructed
+        .iter()
+        .flatten()
+        .zip(a.to_rows().iter().flatten())
+    {
+        assert!((r - e).abs() < 1e-5, "expected {}, got {}", e, r);
+    }
+    assert!((svd.u.determinant() - 1.0).abs() < 1e-5);
+    assert!((svd.v.determinant() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn ill_conditioned_svd_works_in_single_precision() {
+    // Singular values 1, 1e-2 and 1e-4 between two rotations.
+    let left = Matrix::rotation(0.7_f32, &Vector::new(1.0, 2.0, 2.0).normalize());
+    let right = Matrix::rotation(-1.3_f32, &Vector::y_axis());
+    let sigma = Matrix::new(1.0, 0.0, 0.0, 0.0, 1e-2, 0.0, 0.0, 0.0, 1e-4);
+    let a = &left * sigma * right.transpose();
+    let svd = a.svd();
+
+    let reconstructed = (&svd.u * svd.sigma() * svd.v.transpose()).to_rows();
+    for (r, e) in reconstructed
+        .iter()
+        .flatten()
+        .zip(a.to_rows().iter().flatten())
+    {
+        assert!((r - e).abs() < 1e-6, "expected {}, got {}", e, r);
+    }
+    for m in [&svd.u, &svd.v] {
+        let gram = (m.transpose() * m - Matrix::unity()).to_rows();
+        assert!(gram.iter().flatten().all(|e| e.abs() < 1e-6));
+    }
+    let expected = [1.0, 1e-2, 1e-4];
+    let actual = [
+        svd.singular_values.x,
+        svd.singular_values.y,
+        svd.singular_values.z,
+    ];
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-3 * e, "expected {}, got {}", e, a);
+    }
+}
+
+#[test]
+fn polar_decomposition_splits_rotation_and_stretch() {
+    let axis = Vector::new(1.0, -2.0, 0.5).normalize();
+    let stretch = Matrix::from_rows([[2.0, 0.5, 0.0], [0.5, 1.0, 0.2], [0.0, 0.2, 3.0]]);
+    let a = Matrix::rotation(0.8, &axis) * &stretch;
+    let (rotation, actual) = a.polar_decomposition();
+
+    assert_rotation(&rotation);
+    assert_matrix_close(&rotation, &Matrix::rotation(0.8, &axis));
+    assert_matrix_close(&actual, &stretch);
+    assert_matrix_close(&(&rotation * &actual), &a);
+}
+
+#[test]
+fn polar_decomposition_keeps_reflections_in_the_stretch() {
+    let reflection = Matrix::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0);
+    let (rotation, stretch) = reflection.polar_decomposition();
+    assert_matrix_close(&rotation, &Matrix::unity());
+    assert_matrix_close(&stretch, &reflection);
+
+    let axis = Vector::new(0.0, 1.0, 1.0).normalize();
+    let a =
+        Matrix::rotation(1.1, &axis) * Matrix::new(2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -0.5);
+    let (rotation, stretch) = a.polar_decomposition();
+    assert_rotation(&rotation);
+    assert_matrix_close(&stretch, &stretch.transpose());
+    assert_matrix_close(&(&rotation * &stretch), &a);
+    let values = stretch.symmetric_eigen().values;
+    assert!(values.z < 0.0, "eigenvalues {:?}", values);
+}
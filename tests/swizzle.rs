@@ -0,0 +1,59 @@
+use threed::Vector;
+
+// Component values derived from the accessor name, x = 1, y = 2, z = 3.
+fn expected(name: &str) -> Vec<i32> {
+    name.chars()
+        .map(|c| match c {
+            'x' => 1,
+            'y' => 2,
+            'z' => 3,
+            _ => unreachable!("{}", name),
+        })
+        .collect()
+}
+
+macro_rules! assert_swizzles2 {
+    ($v:expr; $($name:ident),*) => {
+        $(
+            let s = $v.$name();
+            assert_eq!(vec![s.x, s.y], expected(stringify!($name)));
+        )*
+    };
+}
+
+macro_rules! assert_swizzles3 {
+    ($v:expr; $($name:ident),*) => {
+        $(
+            let s = $v.$name();
+            assert_eq!(vec![s.x, s.y, s.z], expected(stringify!($name)));
+        )*
+    };
+}
+
+#[test]
+fn two_component_swizzles_pick_the_named_components() {
+    let v = Vector::new(1, 2, 3);
+    assert_swizzles2!(v; xx, xy, xz, yx, yy, yz, zx, zy, zz);
+}
+
+#[test]
+fn three_component_swizzles_pick_the_named_components() {
+    let v = Vector::new(1, 2, 3);
+    assert_swizzles3!(
+        v; xxx, xxy, xxz, xyx, xyy, xyz, xzx, xzy, xzz, yxx, yxy, yxz, yyx, yyy, yyz, yzx, yzy,
+        yzz, zxx, zxy, zxz, zyx, zyy, zyz, zzx, zzy, zzz
+    );
+}
+
+#[test]
+fn swizzles_compose_with_vector_operations() {
+    let v = Vector::new(1.5, -2.0, 4.0);
+
+    assert_eq!(v.xyz(), v);
+    assert_eq!(v.zyx().zyx(), v);
+    assert_eq!(v.xz().extend(v.y).xzy(), v);
+    // A cyclic permutation is a rotation, so it commutes with the cross
+    // product.
+    let w = Vector::new(0.5, 3.0, -1.0);
+    assert_eq!(v.yzx().cross(&w.yzx()), v.cross(&w).yzx());
+}
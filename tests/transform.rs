@@ -0,0 +1,151 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{Isometry, Matrix, Transform, Vector};
+
+mod common;
+use common::{assert_close, assert_matrix4_close, assert_matrix_close, assert_near};
+
+fn axis() -> Vector<f64> {
+    Vector::new(1.0, 2.0, 2.0).normalize()
+}
+
+fn transform() -> Transform<f64> {
+    Transform::new(
+        Vector::new(1.0, -2.0, 3.0),
+        Matrix::rotation(0.7, &axis()),
+        Vector::new(2.0, 0.5, 3.0),
+    )
+}
+
+#[test]
+fn matrix_agrees_with_transform_point() {
+    let t = transform();
+    let p = Vector::new(0.5, -1.0, 2.0);
+
+    assert_close(
+        &t.matrix().transform_point(&p).unwrap(),
+        &t.transform_point(&p),
+    );
+    assert_close(&t.matrix().transform_vector(&p), &t.transform_vector(&p));
+}
+
+#[test]
+fn compose_applies_other_first() {
+    let p = Vector::new(0.5, -1.0, 2.0);
+    let outer = Transform::new(
+        Vector::new(0.0, 1.0, -1.0),
+        Matrix::rotation(-1.2, &Vector::z_axis()),
+        Vector::scalar(1.5),
+    );
+    let inner = transform();
+
+    let composed = outer.compose(&inner).unwrap();
+    assert_close(
+        &composed.transform_point(&p),
+        &outer.transform_point(&inner.transform_point(&p)),
+    );
+    assert_matrix4_close(&composed.matrix(), &(outer.matrix() * inner.matrix()));
+}
+
+#[test]
+fn compose_permutes_non_uniform_scale_through_quarter_turns() {
+    let p = Vector::new(0.5, -1.0, 2.0);
+    let outer = Transform::from_scale(Vector::new(2.0, 3.0, 4.0));
+    let inner = Transform::from_rotation(Matrix::rotation(FRAC_PI_2, &Vector::z_axis()));
+
+    let composed = outer.compose(&inner).unwrap();
+    assert_close(&composed.scale, &Vector::new(3.0, 2.0, 4.0));
+    assert_close(
+        &composed.transform_point(&p),
+        &outer.transform_point(&inner.transform_point(&p)),
+    );
+}
+
+#[test]
+fn compose_rejects_shear() {
+    let outer = Transform::from_scale(Vector::new(2.0, 1.0, 1.0));
+    let inner = Transform::from_rotation(Matrix::rotation(0.5, &Vector::z_axis()));
+    assert_eq!(outer.compose(&inner), None);
+
+    // The other way around the scale is applied before the rotation.
+    let composed = inner.compose(&outer).unwrap();
+    assert_close(&composed.scale, &Vector::new(2.0, 1.0, 1.0));
+}
+
+#[test]
+fn inverse_undoes_uniform_transforms() {
+    let t = Transform::new(
+        Vector::new(1.0, -2.0, 3.0),
+        Matrix::rotation(0.7, &axis()),
+        Vector::scalar(2.5),
+    );
+    let p = Vector::new(0.5, -1.0, 2.0);
+    let inverse = t.inverse().unwrap();
+
+    assert_close(&inverse.transform_point(&t.transform_point(&p)), &p);
+    let identity = inverse.compose(&t).unwrap();
+    assert_close(&identity.translation, &Vector::default());
+    assert_matrix_close(&identity.rotation, &Matrix::unity());
+    assert_close(&identity.scale, &Vector::scalar(1.0));
+
+    assert_eq!(transform().inverse(), None);
+    assert_eq!(Transform::from_scale(Vector::scalar(0.0)).inverse(), None);
+}
+
+#[test]
+fn transformed_normals_stay_perpendicular() {
+    let t = transform();
+    let normal = Vector::new(1.0, 1.0, 0.0).normalize();
+    let tangents = [Vector::new(1.0, -1.0, 0.0), Vector::z_axis()];
+
+    let transformed = t.transform_normal(&normal);
+    assert_near(transformed.magnitude(), 1.0);
+    for tangent in &tangents {
+        assert_near(transformed.dot(&t.transform_vector(tangent)), 0.0);
+    }
+}
+
+#[test]
+fn interpolate_hits_endpoints_and_halves_the_rotation() {
+    let start = Transform::identity();
+    let end = transform();
+
+    let at_start = start.interpolate(&end, 0.0);
+    assert_close(&at_start.translation, &start.translation);
+    assert_matrix_close(&at_start.rotation, &start.rotation);
+    let at_end = start.interpolate(&end, 1.0);
+    assert_close(&at_end.translation, &end.translation);
+    assert_matrix_close(&at_end.rotation, &end.rotation);
+    assert_close(&at_end.scale, &end.scale);
+
+    let half = start.interpolate(&end, 0.5);
+    assert_close(&half.translation, &Vector::new(0.5, -1.0, 1.5));
+    assert_matrix_close(&half.rotation, &Matrix::rotation(0.35, &axis()));
+    assert_close(&half.scale, &Vector::new(1.5, 0.75, 2.0));
+}
+
+#[test]
+fn isometry_composes_and_inverts() {
+    let a = Isometry::new(Vector::new(1.0, -2.0, 3.0), Matrix::rotation(0.7, &axis()));
+    let b = Isometry::new(
+        Vector::new(0.0, 1.0, -1.0),
+        Matrix::rotation(-1.2, &Vector::z_axis()),
+    );
+    let p = Vector::new(0.5, -1.0, 2.0);
+
+    let composed = a.compose(&b);
+    assert_close(
+        &composed.transform_point(&p),
+        &a.transform_point(&b.transform_point(&p)),
+    );
+    assert_matrix4_close(&composed.matrix(), &(a.matrix() * b.matrix()));
+    assert_close(&a.inverse().transform_point(&a.transform_point(&p)), &p);
+    assert_close(&a.transform_normal(&p), &a.transform_vector(&p));
+
+    let half = Isometry::identity().interpolate(&a, 0.5);
+    assert_close(&half.translation, &Vector::new(0.5, -1.0, 1.5));
+    assert_matrix_close(&half.rotation, &Matrix::rotation(0.35, &axis()));
+
+    let transform = Transform::from(a.clone());
+    assert_close(&transform.transform_point(&p), &a.transform_point(&p));
+}
@@ -0,0 +1,114 @@
+use threed::{Triangle, Vector};
+
+mod common;
+use common::{assert_close, assert_near};
+
+// Right angle at `a`, legs of length 4 along x and 3 along y.
+fn right() -> Triangle<f64> {
+    Triangle::new(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(4.0, 0.0, 0.0),
+        Vector::new(0.0, 3.0, 0.0),
+    )
+}
+
+fn collinear() -> Triangle<f64> {
+    Triangle::new(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 1.0, 1.0),
+        Vector::new(3.0, 3.0, 3.0),
+    )
+}
+
+#[test]
+fn barycentric_coordinates_reproduce_the_projection() {
+    let triangle = right();
+    let point = Vector::new(1.0, 1.0, 7.0);
+
+    let uvw = triangle.barycentric(&point).unwrap();
+    assert_close(&uvw, &Vector::new(5.0 / 12.0, 0.25, 1.0 / 3.0));
+    let projected = &triangle.a * uvw.x + &triangle.b * uvw.y + &triangle.c * uvw.z;
+    assert_close(&projected, &Vector::new(1.0, 1.0, 0.0));
+
+    assert_close(
+        &triangle.barycentric(&triangle.b).unwrap(),
+        &Vector::y_axis(),
+    );
+    assert_eq!(collinear().barycentric(&point), None);
+
+    assert!(triangle.contains_point(&point));
+    assert!(triangle.contains_point(&Vector::new(2.0, 0.0, -1.0)));
+    assert!(!triangle.contains_point(&Vector::new(3.0, 3.0, 0.0)));
+    assert!(!collinear().contains_point(&Vector::new(1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn closest_point_covers_every_voronoi_region() {
+    let triangle = right();
+    let cases = [
+        // Vertex regions.
+        (Vector::new(-1.0, -1.0, 2.0), Vector::new(0.0, 0.0, 0.0)),
+        (Vector::new(5.0, -1.0, 1.0), Vector::new(4.0, 0.0, 0.0)),
+        (Vector::new(-1.0, 4.0, 0.0), Vector::new(0.0, 3.0, 0.0)),
+        // Edge regions.
+        (Vector::new(2.0, -1.0, 1.0), Vector::new(2.0, 0.0, 0.0)),
+        (Vector::new(-1.0, 1.0, -2.0), Vector::new(0.0, 1.0, 0.0)),
+        (Vector::new(4.0, 3.0, 0.0), Vector::new(2.56, 1.08, 0.0)),
+        // Face region.
+        (Vector::new(1.0, 1.0, 5.0), Vector::new(1.0, 1.0, 0.0)),
+    ];
+    for (point, expected) in &cases {
+        assert_close(&triangle.closest_point(point), expected);
+    }
+}
+
+#[test]
+fn centers() {
+    let triangle = right();
+    // The hypotenuse is a diameter of the circumcircle.
+    assert_close(
+        &triangle.circumcenter().unwrap(),
+        &Vector::new(2.0, 1.5, 0.0),
+    );
+    // The inradius of a right triangle is (4 + 3 - 5) / 2.
+    assert_close(&triangle.incenter(), &Vector::new(1.0, 1.0, 0.0));
+    assert_close(&triangle.centroid(), &Vector::new(4.0 / 3.0, 1.0, 0.0));
+    assert_eq!(collinear().circumcenter(), None);
+
+    // Equilateral triangles have all centers in one place.
+    let equilateral = Triangle::new(Vector::x_axis(), Vector::y_axis(), Vector::z_axis());
+    let center = Vector::scalar(1.0 / 3.0);
+    assert_close(&equilateral.circumcenter().unwrap(), &center);
+    assert_close(&equilateral.incenter(), &center);
+}
+
+#[test]
+fn normal_and_area() {
+    let triangle = right();
+    assert_close(&triangle.scaled_normal(), &Vector::new(0.0, 0.0, 12.0));
+    assert_close(&triangle.normal(), &Vector::z_axis());
+    assert_near(triangle.area(), 6.0);
+}
+
+#[test]
+fn signed_volumes_sum_to_the_enclosed_volume() {
+    let corner = Triangle::new(Vector::x_axis(), Vector::y_axis(), Vector::z_axis());
+    assert_near(corner.signed_volume(), 1.0 / 6.0);
+    let flipped = Triangle::new(Vector::x_axis(), Vector::z_axis(), Vector::y_axis());
+    assert_near(flipped.signed_volume(), -1.0 / 6.0);
+
+    // Tetrahedron away from the origin with legs of length 2, wound
+    // counter-clockwise seen from outside.
+    let p = [
+        Vector::new(1.0, 1.0, 1.0),
+        Vector::new(3.0, 1.0, 1.0),
+        Vector::new(1.0, 3.0, 1.0),
+        Vector::new(1.0, 1.0, 3.0),
+    ];
+    let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+    let volume: f64 = faces
+        .iter()
+        .map(|[i, j, k]| Triangle::new(p[*i].clone(), p[*j].clone(), p[*k].clone()).signed_volume())
+        .sum();
+    assert_near(volume, 8.0 / 6.0);
+}
@@ -0,0 +1,92 @@
+use std::f64::consts::FRAC_PI_2;
+
+use threed::{Vector, Vector2, Vector4};
+
+mod common;
+use common::assert_near;
+
+#[test]
+fn extend_and_truncate_move_between_dimensions() {
+    let v = Vector2::new(1, 2);
+    let v3 = v.extend(3);
+    assert_eq!(v3, Vector::new(1, 2, 3));
+    assert_eq!(v3.truncate(), v);
+
+    let v4 = v3.extend(4);
+    assert_eq!(v4, Vector4::new(1, 2, 3, 4));
+    assert_eq!(v4.truncate(), v3);
+}
+
+#[test]
+fn vector2_products_and_perpendicular() {
+    let a = Vector2::new(3, 1);
+    let b = Vector2::new(-1, 2);
+
+    assert_eq!(a.dot(&b), -1);
+    assert_eq!(a.perp_dot(&b), 7);
+    assert_eq!(a.perp(), Vector2::new(-1, 3));
+    assert_eq!(a.perp().dot(&a), 0);
+    // perp_dot is the z component of the lifted cross product.
+    assert_eq!(a.extend(0).cross(&b.extend(0)).z, a.perp_dot(&b));
+}
+
+#[test]
+fn vector2_component_wise_operations() {
+    let a = Vector2::new(3, -1);
+    let b = Vector2::new(-1, 2);
+
+    assert_eq!(&a + &b, Vector2::new(2, 1));
+    assert_eq!(&a - &b, Vector2::new(4, -3));
+    assert_eq!(&a * 2, Vector2::new(6, -2));
+    assert_eq!(-&a, Vector2::new(-3, 1));
+    assert_eq!(a.min(&b), Vector2::new(-1, -1));
+    assert_eq!(a.max(&b), Vector2::new(3, 2));
+    assert_eq!(a.abs(), Vector2::new(3, 1));
+    assert_eq!(a.magnitude_squared(), 10);
+    assert_eq!(Vector2::scalar(4), Vector2::new(4, 4));
+    assert_eq!(Vector2::<i32>::x_axis().perp(), Vector2::y_axis());
+}
+
+#[test]
+fn vector2_real_operations() {
+    let a = Vector2::new(3.0, 4.0);
+
+    assert_near(a.magnitude(), 5.0);
+    assert_near(a.normalize().magnitude(), 1.0);
+    assert_near(a.angle(&a.perp()), FRAC_PI_2);
+    let mid = a.lerp(&Vector2::new(1.0, 0.0), 0.5);
+    assert_near(mid.x, 2.0);
+    assert_near(mid.y, 2.0);
+}
+
+#[test]
+fn vector4_component_wise_operations() {
+    let a = Vector4::new(1, -2, 3, -4);
+    let b = Vector4::new(0, 5, -1, 2);
+
+    assert_eq!(a.dot(&b), -21);
+    assert_eq!(&a + &b, Vector4::new(1, 3, 2, -2));
+    assert_eq!(&a - &b, Vector4::new(1, -7, 4, -6));
+    assert_eq!(a.min(&b), Vector4::new(0, -2, -1, -4));
+    assert_eq!(a.max(&b), Vector4::new(1, 5, 3, 2));
+    assert_eq!(a.abs(), Vector4::new(1, 2, 3, 4));
+    assert_eq!(a.magnitude_squared(), 30);
+    let axes = [
+        Vector4::<i32>::x_axis(),
+        Vector4::y_axis(),
+        Vector4::z_axis(),
+        Vector4::w_axis(),
+    ];
+    let sum = axes.iter().fold(Vector4::default(), |sum, axis| sum + axis);
+    assert_eq!(sum, Vector4::scalar(1));
+}
+
+#[test]
+fn vector4_real_operations() {
+    let a = Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    assert_near(a.magnitude(), 2.0);
+    assert_near(a.normalize().w, 0.5);
+    assert_near(Vector4::x_axis().angle(&Vector4::w_axis()), FRAC_PI_2);
+    assert_near(a.lerp(&Vector4::default(), 0.25).z, 0.75);
+}
@@ -0,0 +1,59 @@
+use threed::{Handedness, Matrix4, Vector};
+
+mod common;
+use common::{assert_close, assert_matrix4_close};
+
+fn camera() -> (Vector<f64>, Vector<f64>, Vector<f64>) {
+    let eye = Vector::new(1.0, 2.0, 3.0);
+    let target = Vector::new(4.0, 2.0, -1.0);
+    let up = Vector::new(0.1, 1.0, 0.0);
+    (eye, target, up)
+}
+
+#[test]
+fn look_at_inverse_undoes_look_at() {
+    let (eye, target, up) = camera();
+    for handedness in [Handedness::Right, Handedness::Left] {
+        let view = Matrix4::look_at(&eye, &target, &up, handedness).unwrap();
+        let inverse = Matrix4::look_at_inverse(&eye, &target, &up, handedness).unwrap();
+        assert_matrix4_close(&(&view * &inverse), &Matrix4::unity());
+        assert_matrix4_close(&(&inverse * &view), &Matrix4::unity());
+
+        let direction = &target - &eye;
+        let to = Matrix4::look_to(&eye, &direction, &up, handedness).unwrap();
+        assert_matrix4_close(&to, &view);
+        let to_inverse = Matrix4::look_to_inverse(&eye, &direction, &up, handedness).unwrap();
+        assert_matrix4_close(&to_inverse, &inverse);
+    }
+}
+
+#[test]
+fn forward_is_negative_z_for_right_and_positive_z_for_left_handed() {
+    let (eye, target, up) = camera();
+    // The target is 5 units away from the eye.
+    let cases = [
+        (Handedness::Right, Vector::new(0.0, 0.0, -5.0)),
+        (Handedness::Left, Vector::new(0.0, 0.0, 5.0)),
+    ];
+    for (handedness, expected) in cases {
+        let view = Matrix4::look_at(&eye, &target, &up, handedness).unwrap();
+        assert_close(&view.transform_point(&eye).unwrap(), &Vector::default());
+        assert_close(&view.transform_point(&target).unwrap(), &expected);
+
+        // World up stays in the upper half of the view.
+        let up_in_view = view.transform_vector(&up);
+        assert!(up_in_view.y > 0.0 && up_in_view.x.abs() < 1e-12);
+    }
+}
+
+#[test]
+fn degenerate_cameras_return_none() {
+    let eye = Vector::new(1.0, 2.0, 3.0);
+    let handedness = Handedness::Right;
+
+    let above = Vector::new(1.0, 7.0, 3.0);
+    assert!(Matrix4::look_at(&eye, &above, &Vector::y_axis(), handedness).is_none());
+    assert!(Matrix4::look_at_inverse(&eye, &above, &-Vector::y_axis(), handedness).is_none());
+    assert!(Matrix4::look_at(&eye, &eye, &Vector::y_axis(), handedness).is_none());
+    assert!(Matrix4::look_to(&eye, &Vector::x_axis(), &Vector::default(), handedness).is_none());
+}